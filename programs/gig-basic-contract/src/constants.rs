@@ -1,7 +1,32 @@
 use anchor_lang::prelude::Pubkey;
 
-pub const CONTRACT_SEED: &str = "gig_contract";
+pub const CONTRACT_SEED: &str = "gig_contract"; // seeds: [CONTRACT_SEED, buyer, contract_id]
 pub const CONTRACT_NATIVE_SEED: &str = "gig_contract_native";
+pub const CONFIG_SEED: &str = "gig_config";
+pub const AMENDMENT_SEED: &str = "gig_amendment";
+pub const APPLICATION_SEED: &str = "gig_application";
+pub const PROFILE_SEED: &str = "gig_profile"; // seeds: [PROFILE_SEED, wallet]
+pub const VERIFIED_SEED: &str = "gig_verified"; // seeds: [VERIFIED_SEED, wallet]
+pub const BANNED_SEED: &str = "gig_banned"; // seeds: [BANNED_SEED, wallet]
+pub const REVIEW_SEED: &str = "gig_review"; // seeds: [REVIEW_SEED, contract, reviewer]
+pub const PROFILE_PAGE_SEED: &str = "gig_profile_page"; // seeds: [PROFILE_PAGE_SEED, wallet, page index]
+
+pub const PAUSE_NEW_CONTRACTS: u8 = 1 << 0; // blocks start_contract and activate_contract
+pub const PAUSE_SETTLEMENTS: u8 = 1 << 1; // blocks payouts in seller_approve and admin_approve
+
+pub const PLATFORM_FEE_PERCENT: u64 = 10;
+pub const ARBITER_COLLATERAL_PERCENT: u64 = 50; // part of forfeited collateral paid to the arbiter resolving the contract
+pub const BPS_DENOMINATOR: u16 = 10_000; // seller_share_bps of 10000 releases everything to seller
+pub const MAX_TEAM_SIZE: usize = 5; // sellers sharing a team contract
+pub const MAX_SPLIT_ROUNDS: u8 = 3; // counter-offers allowed before a split escalates to dispute
+pub const MIN_REVIEW_PERIOD: u32 = 24 * 60 * 60; // buyer has at least 1 day to review a delivery
+pub const SPLIT_RESPONSE_WINDOW: u32 = 3 * 24 * 60 * 60; // 3 days to answer a split proposal
+pub const RESOLUTION_DELAY: u32 = 24 * 60 * 60; // guardian can veto an admin decision for 1 day before it is executed
+pub const MAX_APPEAL_ARBITERS: usize = 3; // higher-tier arbiters resolving appeals
+pub const APPEAL_RESOLUTION_WINDOW: u32 = 7 * 24 * 60 * 60; // appeal arbiters have 7 days before the original decision can be executed
+pub const MAX_BATCH_SIZE: usize = 20; // contracts admin_approve_many records in one transaction
+pub const CONTRACT_PAGE_SIZE: usize = 16; // contract addresses per profile page
+pub const MAX_RATING: u8 = 5; // reviews are rated from 1 to MAX_RATING
 
 pub const DECIMAL: u32 = 8; // 8 for BPT, 6 for USDC
 
@@ -17,5 +17,115 @@ pub enum GigContractError {
     #[msg("Contract is not pending or disputed yet so admin can't approve now or already completed!")]
     NotReadyYet,
     #[msg("Invalid payment token!")]
-    PayTokenMintError
+    PayTokenMintError,
+    #[msg("Invalid treasury authority is trying to withdraw fees!")]
+    InvalidTreasuryAuthority,
+    #[msg("Insufficient treasury balance!")]
+    InsufficientTreasuryBalance,
+    #[msg("Invalid pending admin is trying to accept admin role!")]
+    InvalidPendingAdmin,
+    #[msg("Admin transfer timelock has not expired yet!")]
+    AdminTransferLocked,
+    #[msg("Invalid guardian is trying to pause the program!")]
+    InvalidGuardian,
+    #[msg("Creating new contracts is paused!")]
+    NewContractsPaused,
+    #[msg("Settlements are paused!")]
+    SettlementsPaused,
+    #[msg("Contract is already activated so buyer can't cancel it!")]
+    CantCancel,
+    #[msg("Contract is not waiting for activation!")]
+    CantActivate,
+    #[msg("Contract is not an active one-time contract so amount can't be increased!")]
+    CantIncrease,
+    #[msg("Amount should be greater than zero!")]
+    InvalidAmount,
+    #[msg("Only buyer or seller can propose an amendment!")]
+    InvalidProposer,
+    #[msg("Only the counterparty of the proposer can accept an amendment!")]
+    InvalidAmendmentAcceptor,
+    #[msg("Contract is already approved, completed or recurring so it can't be amended!")]
+    CantAmend,
+    #[msg("Seller share should be between 0 and 10000 bps!")]
+    InvalidShare,
+    #[msg("Split proposal has to be answered by the counterparty!")]
+    SplitAwaitingCounterparty,
+    #[msg("Split proposal has expired!")]
+    SplitExpired,
+    #[msg("There is no split proposal to answer!")]
+    NoSplitProposal,
+    #[msg("Split is being negotiated, counter or accept it instead!")]
+    SplitNegotiating,
+    #[msg("Contract is not an active one-time contract so delivery can't be submitted!")]
+    CantDeliver,
+    #[msg("Delivery review period is not over yet!")]
+    ReviewPeriodNotOver,
+    #[msg("Contract is not a recurring contract!")]
+    NotRecurring,
+    #[msg("Period count and interval should be greater than zero!")]
+    InvalidPeriod,
+    #[msg("Next period is not due yet!")]
+    PeriodNotDue,
+    #[msg("Contract is not a streamed contract!")]
+    NotStream,
+    #[msg("Deadline should be after start time!")]
+    InvalidDeadline,
+    #[msg("Nothing is vested to withdraw yet!")]
+    NothingVested,
+    #[msg("Team should have 2 to 5 unique sellers with shares adding up to 10000 bps, lead seller first!")]
+    InvalidTeam,
+    #[msg("Seller already activated this contract!")]
+    AlreadyActivated,
+    #[msg("Team member token accounts should be passed in team order!")]
+    InvalidTeamAccount,
+    #[msg("Job is not open for applications!")]
+    JobNotOpen,
+    #[msg("Buyer can't apply to its own job!")]
+    InvalidApplicant,
+    #[msg("Bid should be greater than zero and not above the job amount!")]
+    InvalidBid,
+    #[msg("Only the lowest bid can be awarded by others after bid deadline!")]
+    NotLowestBid,
+    #[msg("Lowest bid can't be withdrawn while the job is open!")]
+    LowestBidLocked,
+    #[msg("Only completed contracts can be reviewed!")]
+    CantReview,
+    #[msg("Rating should be between 1 and 5!")]
+    InvalidRating,
+    #[msg("Only contract parties can review each other!")]
+    InvalidReviewer,
+    #[msg("Invalid verifier!")]
+    InvalidVerifier,
+    #[msg("Seller doesn't hold a valid verification!")]
+    NotVerified,
+    #[msg("Wallet is banned!")]
+    WalletBanned,
+    #[msg("Invalid arbiter!")]
+    InvalidArbiter,
+    #[msg("Contract decision can't be appealed!")]
+    CantAppeal,
+    #[msg("Only the losing party can appeal the decision!")]
+    InvalidAppellant,
+    #[msg("Resolution can't be executed yet!")]
+    ResolutionNotReady,
+    #[msg("Invalid appeal arbiter!")]
+    InvalidAppealArbiter,
+    #[msg("Contract decision is not appealed!")]
+    NotAppealed,
+    #[msg("Only recorded decisions can be vetoed!")]
+    CantVeto,
+    #[msg("Too many contracts in one batch!")]
+    BatchTooLarge,
+    #[msg("Invalid contract account in batch!")]
+    InvalidBatchAccount,
+    #[msg("Contract changed since the amendment was proposed!")]
+    AmendmentOutdated,
+    #[msg("Review period is too short!")]
+    InvalidReviewPeriod,
+    #[msg("Appeal can still be resolved by appeal arbiters!")]
+    AppealNotExpired,
+    #[msg("Invalid pending treasury authority is trying to accept the role!")]
+    InvalidPendingTreasuryAuthority,
+    #[msg("Treasury authority transfer timelock has not expired yet!")]
+    TreasuryTransferLocked
 }
\ No newline at end of file
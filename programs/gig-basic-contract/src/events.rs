@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::state::contract::Payout;
+
+// Emitted on every payout out of a contract, platform fee and arbiter fee are reported separately
+#[event]
+pub struct ContractSettled {
+    pub contract: Pubkey,
+    pub seller: u64, // paid for the work, excluding returned collateral
+    pub buyer: u64, // refund including returned collateral
+    pub platform_fee: u64,
+    pub forfeited: u64, // collateral kept by the treasury
+    pub arbiter: Pubkey, // default unless resolved in admin_approve
+    pub arbiter_fee: u64,
+}
+
+impl ContractSettled {
+    pub fn new(contract: Pubkey, payout: &Payout, arbiter: Pubkey) -> Self {
+        ContractSettled {
+            contract,
+            seller: payout.seller,
+            buyer: payout.buyer,
+            platform_fee: payout.platform_fee,
+            forfeited: payout.treasury - payout.platform_fee,
+            arbiter,
+            arbiter_fee: payout.arbiter,
+        }
+    }
+}
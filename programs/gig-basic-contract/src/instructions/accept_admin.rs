@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::state::config::*;
+use crate::constants::{
+    CONFIG_SEED
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn accept_admin(
+    ctx: Context<AcceptAdminContext>,
+) -> Result<()> {
+    msg!("Accepting admin role!");
+
+    let config = &mut ctx.accounts.config;
+
+    // Check if the signer is the proposed admin
+    require!(config.pending_admin != Pubkey::default(), GigContractError::InvalidPendingAdmin);
+    require_keys_eq!(ctx.accounts.new_admin.key(), config.pending_admin, GigContractError::InvalidPendingAdmin);
+
+    // Check if the timelock has passed
+    let current_timestamp = Clock::get()?.unix_timestamp as u32;
+    require!(current_timestamp >= config.admin_transfer_ready_at, GigContractError::AdminTransferLocked);
+
+    config.admin = config.pending_admin;
+    config.pending_admin = Pubkey::default();
+    config.admin_transfer_ready_at = 0;
+
+    msg!("Admin role accepted successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdminContext<'info> {
+    pub new_admin: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
+    )]
+    pub config: Account<'info, Config>,
+}
@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{ self, Token, TokenAccount, Transfer as SplTransfer }
+};
+
+use crate::state::contract::*;
+use crate::state::config::*;
+use crate::state::amendment::*;
+use crate::constants::{
+    CONTRACT_SEED,
+    CONFIG_SEED,
+    AMENDMENT_SEED,
+    PAUSE_SETTLEMENTS,
+    PAY_TOKEN_MINT_ADDRESS
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn accept_amendment(
+    ctx: Context<AcceptAmendmentContext>,
+    _contract_id: String,
+) -> Result<()> {
+    msg!("Accepting amendment!");
+
+    let contract = &mut ctx.accounts.contract;
+    let amendment = &ctx.accounts.amendment;
+    let acceptor = ctx.accounts.acceptor.key();
+
+    // Check if the signer is the counterparty of the proposer
+    require!(acceptor == contract.buyer || acceptor == contract.seller, GigContractError::InvalidAmendmentAcceptor);
+    require_keys_neq!(acceptor, amendment.proposer, GigContractError::InvalidAmendmentAcceptor);
+
+    // Terms can only be changed before any party approved
+    require!(contract.status == ContractStatus::Created || contract.status == ContractStatus::Active, GigContractError::CantAmend);
+    require!(contract.kind == ContractKind::OneTime, GigContractError::CantAmend);
+
+    // Contract terms may have changed since the proposal, e.g. through increase_amount
+    require!(contract.amount == amendment.base_amount && contract.deadline == amendment.base_deadline, GigContractError::AmendmentOutdated);
+
+    // Refunds are still allowed in Created state while settlements are paused
+    require!(contract.status == ContractStatus::Created || !ctx.accounts.config.is_paused(PAUSE_SETTLEMENTS), GigContractError::SettlementsPaused);
+
+    let token_program = &ctx.accounts.token_program;
+    let source = &ctx.accounts.contract_ata;
+    let buyer_destination = &ctx.accounts.buyer_ata;
+
+    let refund = contract.amount - amendment.amount;
+
+    contract.amount = amendment.amount;
+    contract.deadline = amendment.deadline;
+
+    // Refund the decreased amount to buyer
+    if refund > 0 {
+        token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            SplTransfer {
+                from: source.to_account_info().clone(),
+                to: buyer_destination.to_account_info().clone(),
+                authority: contract.to_account_info().clone(),
+            },
+            &[&[CONTRACT_SEED.as_bytes(), contract.buyer.as_ref(), contract.contract_id.as_bytes(), &[ctx.bumps.contract]]],
+        ),
+        refund,
+        )?;
+    }
+
+    msg!("Amendment applied successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(contract_id: String)]
+pub struct AcceptAmendmentContext<'info> {
+    pub acceptor: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds = [
+            CONTRACT_SEED.as_bytes(), 
+            contract.buyer.as_ref(),
+            contract_id.as_bytes()
+        ], 
+        bump, 
+    )]
+    pub contract: Account<'info, Contract>,
+
+    #[account(
+        mut, 
+        seeds = [
+            AMENDMENT_SEED.as_bytes(), 
+            contract.key().as_ref()
+        ], 
+        bump = amendment.bump, 
+        close = proposer,
+    )]
+    pub amendment: Account<'info, Amendment>,
+
+    // Rent of the amendment account goes back to whoever proposed it
+    #[account(mut, address = amendment.proposer)]
+    pub proposer: SystemAccount<'info>,
+
+    #[account(
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = contract.buyer,
+    )]
+    pub buyer_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = contract,
+    )]
+    pub contract_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
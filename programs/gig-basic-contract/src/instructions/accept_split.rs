@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{ Token, TokenAccount }
+};
+
+use crate::state::contract::*;
+use crate::state::config::*;
+use crate::state::profile::*;
+use crate::events::ContractSettled;
+use crate::utils::{ transfer_from_contract, pay_sellers };
+use crate::constants::{
+    CONTRACT_SEED,
+    PROFILE_SEED,
+    CONFIG_SEED,
+    PAUSE_SETTLEMENTS,
+    PAY_TOKEN_MINT_ADDRESS
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn accept_split<'info>(
+    ctx: Context<'_, '_, '_, 'info, AcceptSplitContext<'info>>,
+    _contract_id: String,
+) -> Result<()> {
+    msg!("Accepting split proposal!");
+
+    let contract = &mut ctx.accounts.contract;
+    let signer = ctx.accounts.signer.key();
+
+    // Check if the signer is one of the contract parties
+    require!(signer == contract.buyer || signer == contract.seller, GigContractError::InvalidProposer);
+
+    // Check if settlements are paused
+    require!(!ctx.accounts.config.is_paused(PAUSE_SETTLEMENTS), GigContractError::SettlementsPaused);
+
+    // Check if there is a live split proposal from the other party
+    require!(contract.status == ContractStatus::Pending, GigContractError::CantRelease);
+    require!(contract.is_split_negotiating(), GigContractError::NoSplitProposal);
+    require_keys_neq!(signer, contract.split_proposer, GigContractError::SplitAwaitingCounterparty);
+
+    let current_timestamp = Clock::get()?.unix_timestamp as u32;
+    require!(!contract.is_split_expired(current_timestamp), GigContractError::SplitExpired);
+
+    let token_program = &ctx.accounts.token_program;
+    let source = &ctx.accounts.contract_ata;
+    let seller_destination = &ctx.accounts.seller_ata;
+    let buyer_destination = &ctx.accounts.buyer_ata;
+    let treasury_destination = &ctx.accounts.treasury_ata;
+
+    // Both parties agree with the split, dispute back to both party and platform fee to treasury
+    contract.status = ContractStatus::Completed;
+    contract.buyer_approved = true;
+    contract.seller_approved = true;
+    contract.seller_satisfied = true;
+
+    let payout = contract.payout(contract.seller_share_bps, contract.dispute, contract.dispute);
+    let volume = contract.paid_volume(&payout);
+    ctx.accounts.buyer_profile.record_completion(volume);
+    ctx.accounts.seller_profile.record_completion(volume);
+
+    pay_sellers(token_program, contract, source, seller_destination, ctx.remaining_accounts, ctx.bumps.contract, &payout)?;
+    emit!(ContractSettled::new(contract.key(), &payout, Pubkey::default()));
+    transfer_from_contract(token_program, contract, source, buyer_destination, ctx.bumps.contract, payout.buyer)?;
+    transfer_from_contract(token_program, contract, source, treasury_destination, ctx.bumps.contract, payout.treasury)?;
+
+    msg!("Funds released by split agreement successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(contract_id: String)]
+pub struct AcceptSplitContext<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds = [
+            CONTRACT_SEED.as_bytes(), 
+            contract.buyer.as_ref(),
+            contract_id.as_bytes()
+        ], 
+        bump, 
+    )]
+    pub contract: Account<'info, Contract>,
+
+    #[account(
+        mut, 
+        seeds = [
+            PROFILE_SEED.as_bytes(), 
+            contract.buyer.as_ref()
+        ], 
+        bump = buyer_profile.bump, 
+    )]
+    pub buyer_profile: Account<'info, UserProfile>,
+
+    // Lead seller's profile for team contracts
+    #[account(
+        mut, 
+        seeds = [
+            PROFILE_SEED.as_bytes(), 
+            contract.seller.as_ref()
+        ], 
+        bump = seller_profile.bump, 
+    )]
+    pub seller_profile: Account<'info, UserProfile>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = contract.seller,
+    )]
+    pub seller_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = contract.buyer,
+    )]
+    pub buyer_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = config,
+    )]
+    pub treasury_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = contract,
+    )]
+    pub contract_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::state::config::*;
+use crate::constants::{
+    CONFIG_SEED
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn accept_treasury_authority(
+    ctx: Context<AcceptTreasuryAuthorityContext>,
+) -> Result<()> {
+    msg!("Accepting treasury authority!");
+
+    let config = &mut ctx.accounts.config;
+
+    // Check if the signer is the proposed treasury authority
+    require!(config.pending_treasury_authority != Pubkey::default(), GigContractError::InvalidPendingTreasuryAuthority);
+    require_keys_eq!(ctx.accounts.new_treasury_authority.key(), config.pending_treasury_authority, GigContractError::InvalidPendingTreasuryAuthority);
+
+    // Check if the timelock has passed
+    let current_timestamp = Clock::get()?.unix_timestamp as u32;
+    require!(current_timestamp >= config.treasury_transfer_ready_at, GigContractError::TreasuryTransferLocked);
+
+    config.treasury_authority = config.pending_treasury_authority;
+    config.pending_treasury_authority = Pubkey::default();
+    config.treasury_transfer_ready_at = 0;
+
+    msg!("Treasury authority accepted successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptTreasuryAuthorityContext<'info> {
+    pub new_treasury_authority: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
+    )]
+    pub config: Account<'info, Config>,
+}
@@ -6,8 +6,17 @@ use anchor_spl::{
 use std::mem::size_of;
 
 use crate::state::contract::*;
+use crate::state::config::*;
+use crate::state::profile::*;
+use crate::state::verified::*;
 use crate::constants::{
     CONTRACT_SEED,
+    CONFIG_SEED,
+    PROFILE_SEED,
+    PROFILE_PAGE_SEED,
+    VERIFIED_SEED,
+    BANNED_SEED,
+    PAUSE_NEW_CONTRACTS,
     PAY_TOKEN_MINT_ADDRESS
 };
 use crate::errors::{
@@ -18,19 +27,68 @@ use crate::errors::{
 pub fn activate_contract(
     ctx: Context<ActivateContractContext>,
     contract_id: String,
+    arbiter: Option<Pubkey>, // seller accepts the arbiter buyer named, None for the platform admin
 ) -> Result<()> {
     msg!("Activating contact on seller side!");
     let contract = &mut ctx.accounts.contract;
 
-    // Check if the signer is a correct seller
-    require_keys_eq!(ctx.accounts.seller.key(), contract.seller, GigContractError::InvalidActivator);
+    // Check if creating new contracts is paused
+    require!(!ctx.accounts.config.is_paused(PAUSE_NEW_CONTRACTS), GigContractError::NewContractsPaused);
+
+    // Check if the seller or the buyer is banned, buyer may be banned after creating the contract
+    require!(ctx.accounts.seller_ban.data_is_empty(), GigContractError::WalletBanned);
+    require!(ctx.accounts.buyer_ban.data_is_empty(), GigContractError::WalletBanned);
+
+    // Check if the contract is waiting for activation, it may be cancelled by buyer already
+    require!(contract.status == ContractStatus::Created, GigContractError::CantActivate);
+
+    // Check if the seller agrees with the arbiter
+    require_keys_eq!(arbiter.unwrap_or_default(), contract.arbiter, GigContractError::InvalidArbiter);
+
+    // Check if the seller holds a valid attestation when the buyer requires it
+    if contract.require_verified {
+        let current_timestamp = Clock::get()?.unix_timestamp as u32;
+        let verified = ctx.accounts.verified.as_ref().ok_or(GigContractError::NotVerified)?;
+        require!(verified.is_valid(current_timestamp), GigContractError::NotVerified);
+    }
 
     let token_program = &ctx.accounts.token_program;
     let authority = &ctx.accounts.seller;
     let source = &ctx.accounts.seller_ata;
     let destination = &ctx.accounts.contract_ata;
 
-    contract.status = ContractStatus::Active;
+    if contract.is_team() {
+        // Check if the signer is a team member, contract is active once every member posted collateral
+        let team_size = contract.team_size as usize;
+        let member = contract.team[..team_size]
+            .iter_mut()
+            .find(|member| member.seller == authority.key())
+            .ok_or(GigContractError::InvalidActivator)?;
+        require!(!member.activated, GigContractError::AlreadyActivated);
+        member.activated = true;
+
+        if contract.team_members().iter().all(|member| member.activated) {
+            contract.status = ContractStatus::Active;
+        }
+    } else {
+        // Check if the signer is a correct seller
+        require_keys_eq!(authority.key(), contract.seller, GigContractError::InvalidActivator);
+
+        contract.status = ContractStatus::Active;
+    }
+
+    // First period of a recurring contract starts when seller activates it
+    if contract.kind == ContractKind::Recurring && contract.status == ContractStatus::Active {
+        let current_timestamp = Clock::get()?.unix_timestamp as u32;
+        contract.next_release_at = current_timestamp + contract.period_interval;
+    }
+
+    // Stream vests from activation, so the buyer can't make it vest before seller joined
+    if contract.kind == ContractKind::Stream && contract.status == ContractStatus::Active {
+        let current_timestamp = Clock::get()?.unix_timestamp as u32;
+        require!(current_timestamp < contract.deadline, GigContractError::InvalidDeadline);
+        contract.start_time = current_timestamp;
+    }
 
     // Transfer paytoken(amount + dispute) to the contract account
     token::transfer(
@@ -45,6 +103,10 @@ pub fn activate_contract(
     contract.dispute,
     )?;
 
+    // Index the contract in seller's profile, every team member indexes it on its own activation
+    let contract_key = ctx.accounts.contract.key();
+    ctx.accounts.seller_profile.add_contract(&mut ctx.accounts.seller_page, authority.key(), contract_key, false, ctx.bumps.seller_profile, ctx.bumps.seller_page);
+
     msg!("Contract activated successfully!");
     Ok(())
 }
@@ -59,12 +121,77 @@ pub struct ActivateContractContext<'info> {
         mut, 
         seeds = [
             CONTRACT_SEED.as_bytes(), 
+            contract.buyer.as_ref(),
             &contract_id.as_bytes()
         ], 
         bump, 
     )]
     pub contract: Account<'info, Contract>,
 
+    #[account(
+        init_if_needed, 
+        seeds = [
+            PROFILE_SEED.as_bytes(), 
+            seller.key().as_ref()
+        ], 
+        payer = seller, 
+        bump, 
+        space = size_of::<UserProfile>() + 8,
+    )]
+    pub seller_profile: Account<'info, UserProfile>,
+
+    // Current page of seller's contract list, a new one is created every CONTRACT_PAGE_SIZE contracts
+    #[account(
+        init_if_needed, 
+        seeds = [
+            PROFILE_PAGE_SEED.as_bytes(), 
+            seller.key().as_ref(),
+            &seller_profile.page_index().to_le_bytes()
+        ], 
+        payer = seller, 
+        bump, 
+        space = size_of::<ContractPage>() + 8,
+    )]
+    pub seller_page: Account<'info, ContractPage>,
+
+    // Only needed for contracts that require a verified seller
+    #[account(
+        seeds = [
+            VERIFIED_SEED.as_bytes(), 
+            seller.key().as_ref()
+        ], 
+        bump = verified.bump, 
+    )]
+    pub verified: Option<Account<'info, Verified>>,
+
+    /// CHECK: Banned PDA of seller, has to be empty unless the wallet is banned
+    #[account(
+        seeds = [
+            BANNED_SEED.as_bytes(), 
+            seller.key().as_ref()
+        ], 
+        bump, 
+    )]
+    pub seller_ban: UncheckedAccount<'info>,
+
+    /// CHECK: Banned PDA of buyer, has to be empty unless the wallet is banned
+    #[account(
+        seeds = [
+            BANNED_SEED.as_bytes(), 
+            contract.buyer.as_ref()
+        ], 
+        bump, 
+    )]
+    pub buyer_ban: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
+    )]
+    pub config: Account<'info, Config>,
+
     #[account(
         mut, 
         associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
@@ -1,15 +1,11 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{
-    associated_token::AssociatedToken,
-    token::{ self, Mint, Token, TokenAccount, Transfer as SplTransfer }
-};
-use std::mem::size_of;
 
 use crate::state::contract::*;
+use crate::state::config::*;
 use crate::constants::{
     CONTRACT_SEED,
-    ADMIN_ADDRESS,
-    PAY_TOKEN_MINT_ADDRESS
+    CONFIG_SEED,
+    PAUSE_SETTLEMENTS
 };
 use crate::errors::{
     GigContractError
@@ -18,193 +14,57 @@ use crate::errors::{
 
 pub fn admin_approve(
     ctx: Context<AdminApproveContext>,
-    contract_id: String,
+    _contract_id: String,
     decision: u8 // 0 for both ok by default, 1 for seller, 2 for buyer, 3 for split
 ) -> Result<()> {
-    msg!("Releasing funds on seller side!");
+    msg!("Recording decision on admin side!");
 
     let contract = &mut ctx.accounts.contract;
 
-    // Check if the signer is a correct admin
-    require_keys_eq!(ctx.accounts.admin.key(), ADMIN_ADDRESS, GigContractError::InvalidAdmin);
-
-    // Check if the contract is pending which means one of two parties approved.
-    require!(contract.status == ContractStatus::Pending || contract.status == ContractStatus::Dispute, GigContractError::NotReadyYet);
-
-    let token_program = &ctx.accounts.token_program;
-    let source = &ctx.accounts.contract_ata;
-    let seller_destination = &ctx.accounts.seller_ata;
-    let buyer_destination = &ctx.accounts.buyer_ata;
-    let admin_destination = &ctx.accounts.admin_ata;
-
-    // contract.status = ContractStatus::Completed;
-    contract.admin_approved = true;
-
-    let total_balance = source.amount;
-
-    // If buyer is not responding, admin will approve with seller. Admin will get buyer's dispute fee
-   if contract.status == ContractStatus::Pending { 
-        // To seller
-        token::transfer(
-        CpiContext::new_with_signer(
-            token_program.to_account_info(),
-            SplTransfer {
-                from: source.to_account_info().clone(),
-                to: seller_destination.to_account_info().clone(),
-                authority: contract.to_account_info().clone(),
-            },
-            &[&[CONTRACT_SEED.as_bytes(), &contract.contract_id.as_bytes(), &[ctx.bumps.contract]]],
-        ),
-        ((total_balance - 2 * contract.dispute) * 90 / 100 + contract.dispute).try_into().unwrap(),
-        )?;
-    
-        // To admin
-        token::transfer(
-        CpiContext::new_with_signer(
-            token_program.to_account_info(),
-            SplTransfer {
-                from: source.to_account_info().clone(),
-                to: admin_destination.to_account_info().clone(),
-                authority: contract.to_account_info().clone(),
-            },
-            &[&[CONTRACT_SEED.as_bytes(), &contract.contract_id.as_bytes(), &[ctx.bumps.contract]]],
-        ),
-        ((total_balance - 2 * contract.dispute ) * 10 / 100 + contract.dispute).try_into().unwrap(),
-        )?;
-   } else { 
-        // if dispute, perform action based on decision value
-        // 0 for both ok by default, 1 for seller, 2 for buyer, 3 for split
-        
-        match decision {
-            1 => { // admin agrees with seller
-                // transfer payment to seller and admin gets buyer's dispute fee
-                contract.status = ContractStatus::Completed;
-
-                // To seller
-                token::transfer(
-                CpiContext::new_with_signer(
-                    token_program.to_account_info(),
-                    SplTransfer {
-                        from: source.to_account_info().clone(),
-                        to: seller_destination.to_account_info().clone(),
-                        authority: contract.to_account_info().clone(),
-                    },
-                    &[&[CONTRACT_SEED.as_bytes(), &contract.contract_id.as_bytes(), &[ctx.bumps.contract]]],
-                ),
-                ((total_balance - 2 * contract.dispute) * 90 / 100 + contract.dispute).try_into().unwrap(),
-                )?;
-    
-                // To admin
-                token::transfer(
-                CpiContext::new_with_signer(
-                    token_program.to_account_info(),
-                    SplTransfer {
-                        from: source.to_account_info().clone(),
-                        to: admin_destination.to_account_info().clone(),
-                        authority: contract.to_account_info().clone(),
-                    },
-                    &[&[CONTRACT_SEED.as_bytes(), &contract.contract_id.as_bytes(), &[ctx.bumps.contract]]],
-                ),
-                ((total_balance - 2 * contract.dispute ) * 10 / 100 + contract.dispute).try_into().unwrap(),
-                )?;
-            },
-            2 => {
-                // admin agrees with buyer
-                // transfer payment to buyer and admin gets seller's dispute fee
-                contract.status = ContractStatus::Completed;
+    // Check if the signer is the contract arbiter, or the platform admin if there is none
+    require_keys_eq!(ctx.accounts.admin.key(), contract.resolver(ctx.accounts.config.admin), GigContractError::InvalidAdmin);
 
-                // To buyer
-                token::transfer(
-                CpiContext::new_with_signer(
-                    token_program.to_account_info(),
-                    SplTransfer {
-                        from: source.to_account_info().clone(),
-                        to: buyer_destination.to_account_info().clone(),
-                        authority: contract.to_account_info().clone(),
-                    },
-                    &[&[CONTRACT_SEED.as_bytes(), &contract.contract_id.as_bytes(), &[ctx.bumps.contract]]],
-                ),
-                ((total_balance - 2 * contract.dispute) * 90 / 100 + contract.dispute).try_into().unwrap(),
-                )?;
+    // Check if settlements are paused
+    require!(!ctx.accounts.config.is_paused(PAUSE_SETTLEMENTS), GigContractError::SettlementsPaused);
 
-                // To admin
-                token::transfer(
-                CpiContext::new_with_signer(
-                    token_program.to_account_info(),
-                    SplTransfer {
-                        from: source.to_account_info().clone(),
-                        to: admin_destination.to_account_info().clone(),
-                        authority: contract.to_account_info().clone(),
-                    },
-                    &[&[CONTRACT_SEED.as_bytes(), &contract.contract_id.as_bytes(), &[ctx.bumps.contract]]],
-                ),
-                ((total_balance - 2 * contract.dispute ) * 10 / 100 + contract.dispute).try_into().unwrap(),
-                )?;
-            },
-            _ => {
-                // admin agrees with split dicision
-                // split payment and admin gets half of dispute fee from both parties
-                contract.status = ContractStatus::Completed;
-
-                // To seller
-                token::transfer(
-                CpiContext::new_with_signer(
-                    token_program.to_account_info(),
-                    SplTransfer {
-                        from: source.to_account_info().clone(),
-                        to: seller_destination.to_account_info().clone(),
-                        authority: contract.to_account_info().clone(),
-                    },
-                    &[&[CONTRACT_SEED.as_bytes(), &contract.contract_id.as_bytes(), &[ctx.bumps.contract]]],
-                ),
-                ((total_balance - 2 * contract.dispute) * 45 / 100 + contract.dispute / 2).try_into().unwrap(),
-                )?;
-
-                // To buyer
-                token::transfer(
-                CpiContext::new_with_signer(
-                    token_program.to_account_info(),
-                    SplTransfer {
-                        from: source.to_account_info().clone(),
-                        to: buyer_destination.to_account_info().clone(),
-                        authority: contract.to_account_info().clone(),
-                    },
-                    &[&[CONTRACT_SEED.as_bytes(), &contract.contract_id.as_bytes(), &[ctx.bumps.contract]]],
-                ),
-                ((total_balance - 2 * contract.dispute) * 45 / 100 + contract.dispute / 2).try_into().unwrap(),
-                )?;
-
-                // To admin
-                token::transfer(
-                CpiContext::new_with_signer(
-                    token_program.to_account_info(),
-                    SplTransfer {
-                        from: source.to_account_info().clone(),
-                        to: admin_destination.to_account_info().clone(),
-                        authority: contract.to_account_info().clone(),
-                    },
-                    &[&[CONTRACT_SEED.as_bytes(), &contract.contract_id.as_bytes(), &[ctx.bumps.contract]]],
-                ),
-                ((total_balance - 2 * contract.dispute ) * 10 / 100 + contract.dispute).try_into().unwrap(),
-                )?;
-            }
-        }
-   }
-
-    msg!("Funds released by admin successfully!");
+    // Check if the contract is pending which means one of two parties approved,
+    // only one-time contracts can be pending, recurring ones reach admin through object_period
+    require!(contract.status == ContractStatus::Pending || contract.status == ContractStatus::Dispute, GigContractError::NotReadyYet);
+    require!(contract.status == ContractStatus::Dispute || contract.kind == ContractKind::OneTime, GigContractError::NotReadyYet);
+
+    let current_timestamp = Clock::get()?.unix_timestamp as u32;
+
+    // Split negotiation that ran out of time is escalated to dispute,
+    // while an ongoing one has to be answered by the parties first
+    if contract.status == ContractStatus::Pending && contract.is_split_negotiating() {
+        require!(contract.is_split_expired(current_timestamp), GigContractError::NotReadyYet);
+        contract.status = ContractStatus::Dispute;
+    }
+
+    // Decision is only recorded, execute_resolution moves the funds after RESOLUTION_DELAY
+    // so the guardian can veto it, and disputes can be appealed during the appeal window
+    if contract.status == ContractStatus::Dispute {
+        let appeal_window = ctx.accounts.config.appeal_window;
+        contract.record_resolution(decision, ctx.accounts.admin.key(), appeal_window, current_timestamp);
+    } else {
+        // If buyer is not responding, admin will approve with seller. Treasury will get buyer's dispute fee
+        contract.record_resolution(1, ctx.accounts.admin.key(), 0, current_timestamp);
+    }
+
+    msg!("Decision recorded, executable from {}", contract.resolution_ready_at);
     Ok(())
 }
 
 #[derive(Accounts)]
 #[instruction(contract_id: String)]
 pub struct AdminApproveContext<'info> {
-    #[account(mut)]
     pub admin: Signer<'info>,
 
     #[account(
         mut, 
         seeds = [
             CONTRACT_SEED.as_bytes(), 
+            contract.buyer.as_ref(),
             &contract_id.as_bytes()
         ], 
         bump, 
@@ -212,35 +72,10 @@ pub struct AdminApproveContext<'info> {
     pub contract: Account<'info, Contract>,
 
     #[account(
-        mut, 
-        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
-        associated_token::authority = contract.seller,
-    )]
-    pub seller_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut, 
-        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
-        associated_token::authority = contract.buyer,
-    )]
-    pub buyer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut, 
-        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
-        associated_token::authority = ADMIN_ADDRESS,
-    )]
-    pub admin_ata: Account<'info, TokenAccount>,
-
-
-    #[account(
-        mut, 
-        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
-        associated_token::authority = contract,
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
     )]
-    pub contract_ata: Account<'info, TokenAccount>,
-
-    pub token_program: Program<'info, Token>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
-    pub system_program: Program<'info, System>,
+    pub config: Account<'info, Config>,
 }
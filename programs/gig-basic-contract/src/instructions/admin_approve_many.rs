@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::state::contract::*;
+use crate::state::config::*;
+use crate::constants::{
+    CONFIG_SEED,
+    PAUSE_SETTLEMENTS,
+    MAX_BATCH_SIZE
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn admin_approve_many(
+    ctx: Context<AdminApproveManyContext>,
+) -> Result<()> {
+    let contracts = ctx.remaining_accounts;
+    msg!("Recording decisions for {} pending contracts!", contracts.len());
+
+    let admin = ctx.accounts.admin.key();
+    let config = &ctx.accounts.config;
+
+    // Check if settlements are paused
+    require!(!config.is_paused(PAUSE_SETTLEMENTS), GigContractError::SettlementsPaused);
+
+    require!(!contracts.is_empty() && contracts.len() <= MAX_BATCH_SIZE, GigContractError::BatchTooLarge);
+
+    let current_timestamp = Clock::get()?.unix_timestamp as u32;
+
+    for account in contracts {
+        // Owner and discriminator are enough to know it is a contract of this program
+        require!(account.is_writable && account.owner == &crate::ID, GigContractError::InvalidBatchAccount);
+        let mut contract = Contract::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+
+        // Check if the signer is the contract arbiter, or the platform admin if there is none
+        require_keys_eq!(admin, contract.resolver(config.admin), GigContractError::InvalidAdmin);
+
+        // Only pending one-time contracts without a split negotiation, same as admin_approve approving with seller
+        require!(contract.status == ContractStatus::Pending && contract.kind == ContractKind::OneTime, GigContractError::NotReadyYet);
+        require!(!contract.is_split_negotiating(), GigContractError::NotReadyYet);
+
+        contract.record_resolution(1, admin, 0, current_timestamp);
+        contract.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+    }
+
+    msg!("Decisions recorded successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AdminApproveManyContext<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
+    )]
+    pub config: Account<'info, Config>,
+}
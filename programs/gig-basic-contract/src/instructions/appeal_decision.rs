@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{ self, Token, TokenAccount, Transfer as SplTransfer }
+};
+
+use crate::state::contract::*;
+use crate::state::config::*;
+use crate::constants::{
+    CONTRACT_SEED,
+    CONFIG_SEED,
+    APPEAL_RESOLUTION_WINDOW,
+    PAY_TOKEN_MINT_ADDRESS
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn appeal_decision(
+    ctx: Context<AppealDecisionContext>,
+    _contract_id: String,
+) -> Result<()> {
+    msg!("Appealing dispute decision!");
+
+    let contract = &mut ctx.accounts.contract;
+    let appellant = ctx.accounts.appellant.key();
+
+    // Check if the decision is still appealable, it can be appealed only once
+    let current_timestamp = Clock::get()?.unix_timestamp as u32;
+    require!(contract.status == ContractStatus::Resolved, GigContractError::CantAppeal);
+    require!(current_timestamp < contract.appeal_deadline, GigContractError::CantAppeal);
+    require_keys_eq!(contract.appellant, Pubkey::default(), GigContractError::CantAppeal);
+
+    // Check if the signer lost the dispute, either party can appeal a split decision
+    require!(appellant == contract.buyer || appellant == contract.seller, GigContractError::InvalidAppellant);
+    let loser = contract.dispute_loser(contract.decision);
+    require!(loser == Pubkey::default() || loser == appellant, GigContractError::InvalidAppellant);
+
+    let token_program = &ctx.accounts.token_program;
+    let authority = &ctx.accounts.appellant;
+    let source = &ctx.accounts.appellant_ata;
+    let destination = &ctx.accounts.contract_ata;
+    let appeal_bond = ctx.accounts.config.appeal_bond;
+
+    // Settlement is frozen until an appeal arbiter resolves the appeal
+    contract.status = ContractStatus::Appealed;
+    contract.appellant = appellant;
+    contract.appeal_bond = appeal_bond;
+    contract.appeal_resolve_by = current_timestamp + APPEAL_RESOLUTION_WINDOW;
+
+    // Transfer paytoken(appeal bond) to the contract account
+    token::transfer(
+    CpiContext::new(
+        token_program.to_account_info(),
+        SplTransfer {
+            from: source.to_account_info().clone(),
+            to: destination.to_account_info().clone(),
+            authority: authority.to_account_info().clone(),
+        },
+    ),
+    appeal_bond,
+    )?;
+
+    msg!("Dispute decision appealed successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(contract_id: String)]
+pub struct AppealDecisionContext<'info> {
+    #[account(mut)]
+    pub appellant: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds = [
+            CONTRACT_SEED.as_bytes(), 
+            contract.buyer.as_ref(),
+            contract_id.as_bytes()
+        ], 
+        bump, 
+    )]
+    pub contract: Account<'info, Contract>,
+
+    #[account(
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = appellant,
+    )]
+    pub appellant_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = contract,
+    )]
+    pub contract_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
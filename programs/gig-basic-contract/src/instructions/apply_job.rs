@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+
+use crate::state::contract::*;
+use crate::state::application::*;
+use crate::constants::{
+    CONTRACT_SEED,
+    APPLICATION_SEED,
+    BANNED_SEED
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn apply_job(
+    ctx: Context<ApplyJobContext>,
+    _contract_id: String,
+    bid: u64,
+) -> Result<()> {
+    msg!("Applying to job with bid {}", bid);
+
+    let contract = &mut ctx.accounts.contract;
+    let seller = ctx.accounts.seller.key();
+
+    // Check if the job is still open
+    require!(contract.status == ContractStatus::Open, GigContractError::JobNotOpen);
+    require_keys_neq!(seller, contract.buyer, GigContractError::InvalidApplicant);
+    require!(ctx.accounts.seller_ban.data_is_empty(), GigContractError::WalletBanned);
+    require!(bid > 0 && bid <= contract.amount, GigContractError::InvalidBid);
+
+    // Keep track of the lowest bid, first one wins on a tie
+    if contract.lowest_bidder == Pubkey::default() || bid < contract.lowest_bid {
+        contract.lowest_bid = bid;
+        contract.lowest_bidder = seller;
+    }
+
+    let application = &mut ctx.accounts.application;
+
+    application.contract = contract.key();
+    application.seller = seller;
+    application.bid = bid;
+    application.bump = ctx.bumps.application;
+
+    msg!("Applied to job successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(contract_id: String)]
+pub struct ApplyJobContext<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds = [
+            CONTRACT_SEED.as_bytes(), 
+            contract.buyer.as_ref(),
+            contract_id.as_bytes()
+        ], 
+        bump, 
+    )]
+    pub contract: Account<'info, Contract>,
+
+    #[account(
+        init, 
+        seeds = [
+            APPLICATION_SEED.as_bytes(), 
+            contract.key().as_ref(),
+            seller.key().as_ref()
+        ], 
+        payer = seller, 
+        bump, 
+        space = Application::LEN + 8,
+    )]
+    pub application: Account<'info, Application>,
+
+    /// CHECK: Banned PDA of seller, has to be empty unless the wallet is banned
+    #[account(
+        seeds = [
+            BANNED_SEED.as_bytes(), 
+            seller.key().as_ref()
+        ], 
+        bump, 
+    )]
+    pub seller_ban: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{ Token, TokenAccount }
+};
+
+use crate::state::contract::*;
+use crate::state::config::*;
+use crate::state::profile::*;
+use crate::events::ContractSettled;
+use crate::utils::{ transfer_from_contract, pay_sellers };
+use crate::constants::{
+    CONTRACT_SEED,
+    PROFILE_SEED,
+    CONFIG_SEED,
+    PAUSE_SETTLEMENTS,
+    BPS_DENOMINATOR,
+    PAY_TOKEN_MINT_ADDRESS
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn auto_release<'info>(
+    ctx: Context<'_, '_, '_, 'info, AutoReleaseContext<'info>>,
+    _contract_id: String,
+) -> Result<()> {
+    msg!("Auto releasing funds to seller!");
+
+    let contract = &mut ctx.accounts.contract;
+
+    // Check if settlements are paused
+    require!(!ctx.accounts.config.is_paused(PAUSE_SETTLEMENTS), GigContractError::SettlementsPaused);
+
+    // Check if buyer let the review period after delivery pass without approving
+    let current_timestamp = Clock::get()?.unix_timestamp as u32;
+    require!(contract.is_review_over(current_timestamp), GigContractError::ReviewPeriodNotOver);
+
+    let token_program = &ctx.accounts.token_program;
+    let source = &ctx.accounts.contract_ata;
+    let seller_destination = &ctx.accounts.seller_ata;
+    let treasury_destination = &ctx.accounts.treasury_ata;
+
+    // Same as admin approving a pending contract, treasury gets buyer's dispute fee
+    contract.status = ContractStatus::Completed;
+
+    let payout = contract.payout(BPS_DENOMINATOR, contract.dispute, 0);
+    let volume = contract.paid_volume(&payout);
+    ctx.accounts.buyer_profile.record_completion(volume);
+    ctx.accounts.seller_profile.record_completion(volume);
+
+    pay_sellers(token_program, contract, source, seller_destination, ctx.remaining_accounts, ctx.bumps.contract, &payout)?;
+    emit!(ContractSettled::new(contract.key(), &payout, Pubkey::default()));
+    transfer_from_contract(token_program, contract, source, treasury_destination, ctx.bumps.contract, payout.treasury)?;
+
+    msg!("Funds auto released successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(contract_id: String)]
+pub struct AutoReleaseContext<'info> {
+    pub cranker: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds = [
+            CONTRACT_SEED.as_bytes(), 
+            contract.buyer.as_ref(),
+            contract_id.as_bytes()
+        ], 
+        bump, 
+    )]
+    pub contract: Account<'info, Contract>,
+
+    #[account(
+        mut, 
+        seeds = [
+            PROFILE_SEED.as_bytes(), 
+            contract.buyer.as_ref()
+        ], 
+        bump = buyer_profile.bump, 
+    )]
+    pub buyer_profile: Account<'info, UserProfile>,
+
+    // Lead seller's profile for team contracts
+    #[account(
+        mut, 
+        seeds = [
+            PROFILE_SEED.as_bytes(), 
+            contract.seller.as_ref()
+        ], 
+        bump = seller_profile.bump, 
+    )]
+    pub seller_profile: Account<'info, UserProfile>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = contract.seller,
+    )]
+    pub seller_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = config,
+    )]
+    pub treasury_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = contract,
+    )]
+    pub contract_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
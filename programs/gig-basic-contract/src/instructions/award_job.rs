@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{ Token, TokenAccount }
+};
+
+use crate::state::contract::*;
+use crate::state::application::*;
+use crate::state::config::*;
+use crate::utils::transfer_from_contract;
+use crate::constants::{
+    CONTRACT_SEED,
+    APPLICATION_SEED,
+    CONFIG_SEED,
+    PAUSE_SETTLEMENTS,
+    PAY_TOKEN_MINT_ADDRESS
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn award_job(
+    ctx: Context<AwardJobContext>,
+    _contract_id: String,
+) -> Result<()> {
+    msg!("Awarding job!");
+
+    let contract = &mut ctx.accounts.contract;
+    let application = &ctx.accounts.application;
+
+    // Check if settlements are paused, awarding refunds tokens to buyer
+    require!(!ctx.accounts.config.is_paused(PAUSE_SETTLEMENTS), GigContractError::SettlementsPaused);
+
+    // Check if the job is still open
+    require!(contract.status == ContractStatus::Open, GigContractError::JobNotOpen);
+
+    // Buyer can choose any bid, others can only award the lowest bid after bid deadline
+    if ctx.accounts.signer.key() != contract.buyer {
+        let current_timestamp = Clock::get()?.unix_timestamp as u32;
+        require!(current_timestamp > contract.bid_deadline, GigContractError::InvalidBuyer);
+        require_keys_eq!(application.seller, contract.lowest_bidder, GigContractError::NotLowestBid);
+    }
+
+    let token_program = &ctx.accounts.token_program;
+    let source = &ctx.accounts.contract_ata;
+    let buyer_destination = &ctx.accounts.buyer_ata;
+
+    // Job becomes a normal contract bound to the awarded seller at the bid price
+    let refund = contract.amount - application.bid;
+
+    contract.seller = application.seller;
+    contract.amount = application.bid;
+    contract.status = ContractStatus::Created;
+
+    // Refund the difference between ceiling price and winning bid to buyer
+    transfer_from_contract(token_program, contract, source, buyer_destination, ctx.bumps.contract, refund)?;
+
+    msg!("Job awarded successfully to {}", application.seller);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(contract_id: String)]
+pub struct AwardJobContext<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds = [
+            CONTRACT_SEED.as_bytes(), 
+            contract.buyer.as_ref(),
+            contract_id.as_bytes()
+        ], 
+        bump, 
+    )]
+    pub contract: Account<'info, Contract>,
+
+    // Awarded application is closed and its rent goes back to seller
+    #[account(
+        mut, 
+        seeds = [
+            APPLICATION_SEED.as_bytes(), 
+            contract.key().as_ref(),
+            application.seller.as_ref()
+        ], 
+        bump = application.bump, 
+        close = seller,
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(mut, address = application.seller)]
+    pub seller: SystemAccount<'info>,
+
+    #[account(
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = contract.buyer,
+    )]
+    pub buyer_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = contract,
+    )]
+    pub contract_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::state::config::*;
+use crate::state::banned::*;
+use crate::constants::{
+    CONFIG_SEED,
+    BANNED_SEED
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn ban_wallet(
+    ctx: Context<BanWalletContext>,
+    wallet: Pubkey,
+) -> Result<()> {
+    msg!("Banning wallet {}", wallet);
+
+    // Check if the signer is a correct admin
+    require_keys_eq!(ctx.accounts.admin.key(), ctx.accounts.config.admin, GigContractError::InvalidAdmin);
+
+    let banned = &mut ctx.accounts.banned;
+
+    banned.wallet = wallet;
+    banned.banned_at = Clock::get()?.unix_timestamp as u32;
+    banned.bump = ctx.bumps.banned;
+
+    msg!("Wallet banned successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct BanWalletContext<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init, 
+        seeds = [
+            BANNED_SEED.as_bytes(), 
+            wallet.as_ref()
+        ], 
+        payer = admin, 
+        bump, 
+        space = Banned::LEN + 8,
+    )]
+    pub banned: Account<'info, Banned>,
+
+    pub system_program: Program<'info, System>,
+}
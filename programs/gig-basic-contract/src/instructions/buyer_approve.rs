@@ -8,7 +8,7 @@ use std::mem::size_of;
 use crate::state::contract::*;
 use crate::constants::{
     CONTRACT_SEED,
-    ADMIN_ADDRESS,
+    BPS_DENOMINATOR,
     PAY_TOKEN_MINT_ADDRESS
 };
 use crate::errors::{
@@ -19,7 +19,7 @@ use crate::errors::{
 pub fn buyer_approve(
     ctx: Context<BuyerApproveContext>,
     contract_id: String,
-    split: bool
+    seller_share_bps: u16 // 10000 for full release, less for split
 ) -> Result<()> {
     msg!("Releasing funds on buyer side!");
 
@@ -28,20 +28,31 @@ pub fn buyer_approve(
     // Check if the signer is a correct buyer
     require_keys_eq!(ctx.accounts.buyer.key(), contract.buyer, GigContractError::InvalidBuyer);
 
-    // Check if the contract is Active or pending.
-    require!(contract.status == ContractStatus::Active || contract.status == ContractStatus::Pending, GigContractError::CantRelease);
+    // Check if the contract is Active, Delivered or pending.
+    require!(contract.status == ContractStatus::Active || contract.status == ContractStatus::Delivered || contract.status == ContractStatus::Pending, GigContractError::CantRelease);
 
-    let token_program = &ctx.accounts.token_program;
-    let source = &ctx.accounts.contract_ata;
-    let seller_destination = &ctx.accounts.seller_ata;
-    let buyer_destination = &ctx.accounts.buyer_ata;
-    let admin_destination = &ctx.accounts.admin_ata;
+    // Recurring and streamed contracts are paid out per period or as vested, never released at once
+    require!(contract.kind == ContractKind::OneTime, GigContractError::CantRelease);
+
+    require!(seller_share_bps <= BPS_DENOMINATOR, GigContractError::InvalidShare);
+
+    // Ongoing split negotiation has to go through counter_split or accept_split
+    require!(!contract.is_split_negotiating(), GigContractError::SplitNegotiating);
 
     contract.status = ContractStatus::Pending;
     contract.buyer_approved = true;
-    contract.split = split;
-
-    let total_balance = source.amount;
+    contract.split_round = 0;
+    contract.buyer_proposal_bps = seller_share_bps;
+
+    if seller_share_bps == BPS_DENOMINATOR {
+        contract.seller_share_bps = seller_share_bps;
+        contract.split_proposer = Pubkey::default();
+    } else {
+        // Buyer opens a split negotiation, seller accepts, counters or rejects it
+        let current_timestamp = Clock::get()?.unix_timestamp as u32;
+        let buyer = contract.buyer;
+        contract.propose_split(buyer, seller_share_bps, current_timestamp);
+    }
 
     msg!("Funds released by buyer successfully!");
     Ok(())
@@ -57,6 +68,7 @@ pub struct BuyerApproveContext<'info> {
         mut, 
         seeds = [
             CONTRACT_SEED.as_bytes(), 
+            contract.buyer.as_ref(),
             &contract_id.as_bytes()
         ], 
         bump, 
@@ -77,13 +89,6 @@ pub struct BuyerApproveContext<'info> {
     )]
     pub buyer_ata: Account<'info, TokenAccount>,
 
-    #[account(
-        mut, 
-        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
-        associated_token::authority = ADMIN_ADDRESS,
-    )]
-    pub admin_ata: Account<'info, TokenAccount>,
-
 
     #[account(
         mut, 
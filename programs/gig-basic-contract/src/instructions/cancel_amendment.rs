@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::state::contract::*;
+use crate::state::amendment::*;
+use crate::constants::{
+    CONTRACT_SEED,
+    AMENDMENT_SEED
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn cancel_amendment(
+    ctx: Context<CancelAmendmentContext>,
+    _contract_id: String,
+) -> Result<()> {
+    msg!("Cancelling amendment!");
+
+    let contract = &ctx.accounts.contract;
+    let signer = ctx.accounts.signer.key();
+
+    // Proposer withdraws it or the counterparty rejects it
+    require!(signer == contract.buyer || signer == contract.seller, GigContractError::InvalidProposer);
+
+    msg!("Amendment cancelled successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(contract_id: String)]
+pub struct CancelAmendmentContext<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            CONTRACT_SEED.as_bytes(), 
+            contract.buyer.as_ref(),
+            contract_id.as_bytes()
+        ], 
+        bump, 
+    )]
+    pub contract: Account<'info, Contract>,
+
+    #[account(
+        mut, 
+        seeds = [
+            AMENDMENT_SEED.as_bytes(), 
+            contract.key().as_ref()
+        ], 
+        bump = amendment.bump, 
+        close = proposer,
+    )]
+    pub amendment: Account<'info, Amendment>,
+
+    #[account(mut, address = amendment.proposer)]
+    pub proposer: SystemAccount<'info>,
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{ Token, TokenAccount }
+};
+
+use crate::state::contract::*;
+use crate::utils::{ transfer_from_contract, pay_sellers };
+use crate::constants::{
+    CONTRACT_SEED,
+    PAY_TOKEN_MINT_ADDRESS
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn cancel_contract<'info>(
+    ctx: Context<'_, '_, '_, 'info, CancelContractContext<'info>>,
+    _contract_id: String,
+) -> Result<()> {
+    msg!("Cancelling contract on buyer side!");
+
+    let contract = &mut ctx.accounts.contract;
+
+    // Check if the signer is a correct buyer
+    require_keys_eq!(ctx.accounts.buyer.key(), contract.buyer, GigContractError::InvalidBuyer);
+
+    // Only open jobs and contracts the seller hasn't activated yet can be cancelled.
+    // This is not gated by the pause flags so buyers can always get refunded.
+    require!(contract.status == ContractStatus::Created || contract.status == ContractStatus::Open, GigContractError::CantCancel);
+
+    let token_program = &ctx.accounts.token_program;
+    let source = &ctx.accounts.contract_ata;
+    let buyer_destination = &ctx.accounts.buyer_ata;
+
+    contract.status = ContractStatus::Cancelled;
+
+    // Refund paytoken(amount + dispute) to buyer
+    transfer_from_contract(token_program, contract, source, buyer_destination, ctx.bumps.contract, contract.amount + contract.dispute)?;
+
+    // Team members who already activated get their dispute fee back
+    if contract.is_team() {
+        let payout = Payout {
+            seller: 0,
+            seller_collateral: contract.dispute,
+            buyer: 0,
+            treasury: 0,
+            platform_fee: 0,
+            arbiter: 0,
+        };
+        let seller_destination = ctx.accounts.seller_ata.as_ref().ok_or(GigContractError::InvalidTeamAccount)?;
+        pay_sellers(token_program, contract, source, seller_destination, ctx.remaining_accounts, ctx.bumps.contract, &payout)?;
+    }
+
+    msg!("Contract cancelled successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(contract_id: String)]
+pub struct CancelContractContext<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds = [
+            CONTRACT_SEED.as_bytes(), 
+            contract.buyer.as_ref(),
+            contract_id.as_bytes()
+        ], 
+        bump, 
+    )]
+    pub contract: Account<'info, Contract>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = contract.seller,
+    )]
+    pub seller_ata: Option<Account<'info, TokenAccount>>, // only needed to refund team members
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = contract.buyer,
+    )]
+    pub buyer_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = contract,
+    )]
+    pub contract_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::state::contract::*;
+use crate::constants::{
+    CONTRACT_SEED,
+    BPS_DENOMINATOR,
+    MAX_SPLIT_ROUNDS
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn counter_split(
+    ctx: Context<CounterSplitContext>,
+    _contract_id: String,
+    seller_share_bps: u16,
+) -> Result<()> {
+    msg!("Countering split proposal with {} bps for seller", seller_share_bps);
+
+    let contract = &mut ctx.accounts.contract;
+    let signer = ctx.accounts.signer.key();
+
+    // Check if the signer is one of the contract parties
+    require!(signer == contract.buyer || signer == contract.seller, GigContractError::InvalidProposer);
+
+    // Check if there is a split proposal from the other party to counter
+    require!(contract.status == ContractStatus::Pending, GigContractError::CantRelease);
+    require!(contract.is_split_negotiating(), GigContractError::NoSplitProposal);
+    require_keys_neq!(signer, contract.split_proposer, GigContractError::SplitAwaitingCounterparty);
+    require!(seller_share_bps <= BPS_DENOMINATOR, GigContractError::InvalidShare);
+
+    let current_timestamp = Clock::get()?.unix_timestamp as u32;
+
+    if contract.is_split_expired(current_timestamp) || contract.split_round >= MAX_SPLIT_ROUNDS {
+        // Negotiation is over, admin has to decide
+        contract.status = ContractStatus::Dispute;
+        if contract.split_round >= MAX_SPLIT_ROUNDS {
+            contract.dispute_raiser = signer;
+        }
+        msg!("Split negotiation escalated to dispute!");
+        return Ok(());
+    }
+
+    contract.split_round += 1;
+    contract.propose_split(signer, seller_share_bps, current_timestamp);
+
+    msg!("Split counter-offer made successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(contract_id: String)]
+pub struct CounterSplitContext<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds = [
+            CONTRACT_SEED.as_bytes(), 
+            contract.buyer.as_ref(),
+            contract_id.as_bytes()
+        ], 
+        bump, 
+    )]
+    pub contract: Account<'info, Contract>,
+}
@@ -0,0 +1,158 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{ Token, TokenAccount }
+};
+
+use crate::state::contract::*;
+use crate::state::config::*;
+use crate::state::profile::*;
+use crate::events::ContractSettled;
+use crate::utils::{ transfer_from_contract, pay_sellers };
+use crate::constants::{
+    CONTRACT_SEED,
+    PROFILE_SEED,
+    CONFIG_SEED,
+    PAUSE_SETTLEMENTS,
+    PAY_TOKEN_MINT_ADDRESS
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn execute_resolution<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteResolutionContext<'info>>,
+    _contract_id: String,
+) -> Result<()> {
+    msg!("Executing admin decision!");
+
+    let contract = &mut ctx.accounts.contract;
+
+    // Check if settlements are paused
+    require!(!ctx.accounts.config.is_paused(PAUSE_SETTLEMENTS), GigContractError::SettlementsPaused);
+
+    // Check if the decision is recorded, past the veto delay and can't be appealed anymore
+    let current_timestamp = Clock::get()?.unix_timestamp as u32;
+    require!(contract.status == ContractStatus::Resolved, GigContractError::NotReadyYet);
+    require!(current_timestamp >= contract.resolution_ready_at, GigContractError::ResolutionNotReady);
+
+    let token_program = &ctx.accounts.token_program;
+    let source = &ctx.accounts.contract_ata;
+    let seller_destination = &ctx.accounts.seller_ata;
+    let buyer_destination = &ctx.accounts.buyer_ata;
+    let treasury_destination = &ctx.accounts.treasury_ata;
+
+    let payout = contract.dispute_payout(contract.decision);
+
+    // Reputation of both parties, a split decision has no loser
+    if contract.disputed {
+        let loser = contract.dispute_loser(contract.decision);
+        ctx.accounts.buyer_profile.record_dispute(contract.dispute_raiser == contract.buyer, loser == contract.buyer);
+        ctx.accounts.seller_profile.record_dispute(contract.dispute_raiser == contract.seller, loser == contract.seller);
+    }
+
+    contract.status = ContractStatus::Completed;
+    let volume = contract.paid_volume(&payout);
+    ctx.accounts.buyer_profile.record_completion(volume);
+    ctx.accounts.seller_profile.record_completion(volume);
+
+    pay_sellers(token_program, contract, source, seller_destination, ctx.remaining_accounts, ctx.bumps.contract, &payout)?;
+    emit!(ContractSettled::new(contract.key(), &payout, contract.resolved_by));
+    transfer_from_contract(token_program, contract, source, buyer_destination, ctx.bumps.contract, payout.buyer)?;
+    transfer_from_contract(token_program, contract, source, treasury_destination, ctx.bumps.contract, payout.treasury)?;
+
+    if payout.arbiter > 0 {
+        let arbiter_destination = ctx.accounts.arbiter_ata.as_ref().ok_or(GigContractError::InvalidArbiter)?;
+        transfer_from_contract(token_program, contract, source, arbiter_destination, ctx.bumps.contract, payout.arbiter)?;
+    }
+
+    msg!("Funds released by admin decision successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(contract_id: String)]
+pub struct ExecuteResolutionContext<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds = [
+            CONTRACT_SEED.as_bytes(), 
+            contract.buyer.as_ref(),
+            contract_id.as_bytes()
+        ], 
+        bump, 
+    )]
+    pub contract: Account<'info, Contract>,
+
+    #[account(
+        mut, 
+        seeds = [
+            PROFILE_SEED.as_bytes(), 
+            contract.buyer.as_ref()
+        ], 
+        bump = buyer_profile.bump, 
+    )]
+    pub buyer_profile: Account<'info, UserProfile>,
+
+    // Lead seller's profile for team contracts
+    #[account(
+        mut, 
+        seeds = [
+            PROFILE_SEED.as_bytes(), 
+            contract.seller.as_ref()
+        ], 
+        bump = seller_profile.bump, 
+    )]
+    pub seller_profile: Account<'info, UserProfile>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = contract.seller,
+    )]
+    pub seller_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = contract.buyer,
+    )]
+    pub buyer_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = config,
+    )]
+    pub treasury_ata: Account<'info, TokenAccount>,
+
+    // Arbiter fee goes to the arbiter whose decision is executed, only needed when there is one
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = contract.resolved_by,
+    )]
+    pub arbiter_ata: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = contract,
+    )]
+    pub contract_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address,
+    token::{ Token, TokenAccount }
+};
+
+use crate::state::contract::*;
+use crate::state::config::*;
+use crate::state::profile::*;
+use crate::events::ContractSettled;
+use crate::utils::transfer_from_contract;
+use crate::constants::{
+    CONTRACT_SEED,
+    CONFIG_SEED,
+    PAUSE_SETTLEMENTS,
+    MAX_BATCH_SIZE,
+    PAY_TOKEN_MINT_ADDRESS
+};
+use crate::errors::{
+    GigContractError
+};
+
+// Accounts passed per contract: contract, contract_ata, seller_ata, buyer_ata, arbiter_ata, buyer_profile, seller_profile.
+// arbiter_ata is only read when the decision pays an arbiter fee, any account can fill its slot otherwise
+const BATCH_GROUP_SIZE: usize = 7;
+
+
+pub fn execute_resolution_many<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteResolutionManyContext<'info>>,
+) -> Result<()> {
+    let accounts = ctx.remaining_accounts;
+    msg!("Executing admin decisions for {} contracts!", accounts.len() / BATCH_GROUP_SIZE);
+
+    // Check if settlements are paused
+    require!(!ctx.accounts.config.is_paused(PAUSE_SETTLEMENTS), GigContractError::SettlementsPaused);
+
+    require!(accounts.len() % BATCH_GROUP_SIZE == 0, GigContractError::InvalidBatchAccount);
+    require!(!accounts.is_empty() && accounts.len() / BATCH_GROUP_SIZE <= MAX_BATCH_SIZE, GigContractError::BatchTooLarge);
+
+    let token_program = &ctx.accounts.token_program;
+    let treasury_destination = &ctx.accounts.treasury_ata;
+    let current_timestamp = Clock::get()?.unix_timestamp as u32;
+
+    for group in accounts.chunks(BATCH_GROUP_SIZE) {
+        // Owner and discriminator are checked when loading, the address has to be the contract PDA
+        let mut contract = Account::<Contract>::try_from(&group[0])?;
+        let (contract_key, bump) = Pubkey::find_program_address(
+            &[CONTRACT_SEED.as_bytes(), contract.buyer.as_ref(), contract.contract_id.as_bytes()],
+            &crate::ID,
+        );
+        require_keys_eq!(contract.key(), contract_key, GigContractError::InvalidBatchAccount);
+
+        // Check if the decision is recorded, past the veto delay and can't be appealed anymore
+        require!(contract.status == ContractStatus::Resolved, GigContractError::NotReadyYet);
+        require!(current_timestamp >= contract.resolution_ready_at, GigContractError::ResolutionNotReady);
+
+        // Team contracts need token accounts of every member, they go through execute_resolution
+        require!(!contract.is_team(), GigContractError::InvalidBatchAccount);
+
+        let source = Account::<TokenAccount>::try_from(&group[1])?;
+        let seller_destination = Account::<TokenAccount>::try_from(&group[2])?;
+        let buyer_destination = Account::<TokenAccount>::try_from(&group[3])?;
+        require_keys_eq!(source.key(), get_associated_token_address(&contract.key(), &PAY_TOKEN_MINT_ADDRESS), GigContractError::InvalidBatchAccount);
+        require_keys_eq!(seller_destination.key(), get_associated_token_address(&contract.seller, &PAY_TOKEN_MINT_ADDRESS), GigContractError::InvalidBatchAccount);
+        require_keys_eq!(buyer_destination.key(), get_associated_token_address(&contract.buyer, &PAY_TOKEN_MINT_ADDRESS), GigContractError::InvalidBatchAccount);
+
+        let mut buyer_profile = Account::<UserProfile>::try_from(&group[5])?;
+        let mut seller_profile = Account::<UserProfile>::try_from(&group[6])?;
+        require_keys_eq!(buyer_profile.wallet, contract.buyer, GigContractError::InvalidBatchAccount);
+        require_keys_eq!(seller_profile.wallet, contract.seller, GigContractError::InvalidBatchAccount);
+
+        let payout = contract.dispute_payout(contract.decision);
+
+        // Reputation of both parties, a split decision has no loser
+        if contract.disputed {
+            let loser = contract.dispute_loser(contract.decision);
+            buyer_profile.record_dispute(contract.dispute_raiser == contract.buyer, loser == contract.buyer);
+            seller_profile.record_dispute(contract.dispute_raiser == contract.seller, loser == contract.seller);
+        }
+
+        contract.status = ContractStatus::Completed;
+        let volume = contract.paid_volume(&payout);
+        buyer_profile.record_completion(volume);
+        seller_profile.record_completion(volume);
+
+        transfer_from_contract(token_program, &contract, &source, &seller_destination, bump, payout.seller + payout.seller_collateral)?;
+        emit!(ContractSettled::new(contract.key(), &payout, contract.resolved_by));
+        transfer_from_contract(token_program, &contract, &source, &buyer_destination, bump, payout.buyer)?;
+        transfer_from_contract(token_program, &contract, &source, treasury_destination, bump, payout.treasury)?;
+
+        if payout.arbiter > 0 {
+            let arbiter_destination = Account::<TokenAccount>::try_from(&group[4])?;
+            require_keys_eq!(arbiter_destination.key(), get_associated_token_address(&contract.resolved_by, &PAY_TOKEN_MINT_ADDRESS), GigContractError::InvalidBatchAccount);
+            transfer_from_contract(token_program, &contract, &source, &arbiter_destination, bump, payout.arbiter)?;
+        }
+
+        // Accounts loaded by hand are written back here, a contract repeated in the batch is then already completed
+        contract.exit(&crate::ID)?;
+        buyer_profile.exit(&crate::ID)?;
+        seller_profile.exit(&crate::ID)?;
+    }
+
+    msg!("Funds released by admin decisions successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExecuteResolutionManyContext<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = config,
+    )]
+    pub treasury_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{ Token, TokenAccount }
+};
+
+use crate::state::contract::*;
+use crate::state::config::*;
+use crate::utils::transfer_from_contract;
+use crate::constants::{
+    CONTRACT_SEED,
+    CONFIG_SEED,
+    PAUSE_SETTLEMENTS,
+    RESOLUTION_DELAY,
+    PAY_TOKEN_MINT_ADDRESS
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn expire_appeal(
+    ctx: Context<ExpireAppealContext>,
+    _contract_id: String,
+) -> Result<()> {
+    msg!("Expiring unresolved appeal!");
+
+    let contract = &mut ctx.accounts.contract;
+
+    // Check if settlements are paused
+    require!(!ctx.accounts.config.is_paused(PAUSE_SETTLEMENTS), GigContractError::SettlementsPaused);
+
+    // Check if the appeal arbiters failed to resolve the appeal in time
+    let current_timestamp = Clock::get()?.unix_timestamp as u32;
+    require!(contract.status == ContractStatus::Appealed, GigContractError::NotAppealed);
+    require!(current_timestamp >= contract.appeal_resolve_by, GigContractError::AppealNotExpired);
+
+    let token_program = &ctx.accounts.token_program;
+    let source = &ctx.accounts.contract_ata;
+    let appellant_destination = &ctx.accounts.appellant_ata;
+
+    // Original decision stands and can be executed by execute_resolution after the veto delay
+    contract.status = ContractStatus::Resolved;
+    contract.appeal_deadline = current_timestamp;
+    contract.resolution_ready_at = current_timestamp + RESOLUTION_DELAY;
+
+    // Appellant is not at fault, so the bond is returned
+    transfer_from_contract(token_program, contract, source, appellant_destination, ctx.bumps.contract, contract.appeal_bond)?;
+
+    msg!("Appeal expired successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(contract_id: String)]
+pub struct ExpireAppealContext<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds = [
+            CONTRACT_SEED.as_bytes(), 
+            contract.buyer.as_ref(),
+            contract_id.as_bytes()
+        ], 
+        bump, 
+    )]
+    pub contract: Account<'info, Contract>,
+
+    #[account(
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = contract.appellant,
+    )]
+    pub appellant_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = contract,
+    )]
+    pub contract_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{ self, Token, TokenAccount, Transfer as SplTransfer }
+};
+
+use crate::state::contract::*;
+use crate::state::config::*;
+use crate::constants::{
+    CONTRACT_SEED,
+    CONFIG_SEED,
+    PAUSE_NEW_CONTRACTS,
+    PAY_TOKEN_MINT_ADDRESS
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn increase_amount(
+    ctx: Context<IncreaseAmountContext>,
+    _contract_id: String,
+    additional_amount: u64,
+) -> Result<()> {
+    msg!("Increasing contract amount by {}", additional_amount);
+
+    let contract = &mut ctx.accounts.contract;
+
+    // Check if both signers are the correct buyer and seller.
+    // Seller counter-signs so the scope change is agreed by both parties.
+    require_keys_eq!(ctx.accounts.buyer.key(), contract.buyer, GigContractError::InvalidBuyer);
+    require_keys_eq!(ctx.accounts.seller.key(), contract.seller, GigContractError::InvalidSeller);
+
+    // Check if adding funds to escrow is paused
+    require!(!ctx.accounts.config.is_paused(PAUSE_NEW_CONTRACTS), GigContractError::NewContractsPaused);
+
+    // Only active contracts can be topped up, approvals are already based on the current amount
+    require!(contract.status == ContractStatus::Active && contract.kind == ContractKind::OneTime, GigContractError::CantIncrease);
+    require!(additional_amount > 0, GigContractError::InvalidAmount);
+
+    let token_program = &ctx.accounts.token_program;
+    let authority = &ctx.accounts.buyer;
+    let source = &ctx.accounts.buyer_ata;
+    let destination = &ctx.accounts.contract_ata;
+
+    contract.amount += additional_amount;
+
+    // Transfer additional paytoken to the contract account
+    token::transfer(
+    CpiContext::new(
+        token_program.to_account_info(),
+        SplTransfer {
+            from: source.to_account_info().clone(),
+            to: destination.to_account_info().clone(),
+            authority: authority.to_account_info().clone(),
+        },
+    ),
+    additional_amount,
+    )?;
+
+    msg!("Contract amount increased successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(contract_id: String)]
+pub struct IncreaseAmountContext<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    pub seller: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds = [
+            CONTRACT_SEED.as_bytes(), 
+            contract.buyer.as_ref(),
+            contract_id.as_bytes()
+        ], 
+        bump, 
+    )]
+    pub contract: Account<'info, Contract>,
+
+    #[account(
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = buyer,
+    )]
+    pub buyer_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = contract,
+    )]
+    pub contract_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{ Mint, Token, TokenAccount }
+};
+
+use crate::state::config::*;
+use crate::constants::{
+    CONFIG_SEED,
+    ADMIN_ADDRESS,
+    PAY_TOKEN_MINT_ADDRESS
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn initialize_config(
+    ctx: Context<InitializeConfigContext>,
+    treasury_authority: Pubkey,
+    admin_timelock: u32,
+    guardian: Pubkey,
+    verifier: Pubkey,
+) -> Result<()> {
+    msg!("Initializing program config!");
+
+    // Check if the signer is a correct admin
+    require_keys_eq!(ctx.accounts.admin.key(), ADMIN_ADDRESS, GigContractError::InvalidAdmin);
+
+    let config = &mut ctx.accounts.config;
+
+    // Deployer admin is the first arbiter, it can be rotated later
+    config.admin = ctx.accounts.admin.key();
+    config.pending_admin = Pubkey::default();
+    config.admin_timelock = admin_timelock;
+    config.admin_transfer_ready_at = 0;
+    config.treasury_authority = treasury_authority;
+    config.pending_treasury_authority = Pubkey::default();
+    config.treasury_transfer_ready_at = 0;
+    config.guardian = guardian;
+    config.verifier = verifier;
+    config.appeal_window = 0; // appeals are disabled until set_appeal_config
+    config.max_arbiter_fee_bps = 0; // arbiters only get forfeited collateral until set_max_arbiter_fee
+    config.paused = 0;
+    config.bump = ctx.bumps.config;
+
+    msg!("Program config initialized successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeConfigContext<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init, 
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        payer = admin, 
+        bump, 
+        space = Config::LEN + 8,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(address = PAY_TOKEN_MINT_ADDRESS @ GigContractError::PayTokenMintError)]
+    pub pay_token_mint: Account<'info, Mint>,
+
+    // Platform fees are collected here, owned by the config PDA instead of the admin key
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = pay_token_mint,
+        associated_token::authority = config,
+    )]
+    pub treasury_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::state::config::*;
+use crate::state::verified::*;
+use crate::constants::{
+    CONFIG_SEED,
+    VERIFIED_SEED
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn issue_verification(
+    ctx: Context<IssueVerificationContext>,
+    wallet: Pubkey,
+    expires_at: u32,
+) -> Result<()> {
+    msg!("Issuing verification for {}", wallet);
+
+    // Check if the signer is a correct verifier
+    require_keys_eq!(ctx.accounts.verifier.key(), ctx.accounts.config.verifier, GigContractError::InvalidVerifier);
+
+    // Issuing again renews the attestation
+    let verified = &mut ctx.accounts.verified;
+
+    verified.wallet = wallet;
+    verified.expires_at = expires_at;
+    verified.bump = ctx.bumps.verified;
+
+    msg!("Verification issued successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct IssueVerificationContext<'info> {
+    #[account(mut)]
+    pub verifier: Signer<'info>,
+
+    #[account(
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init_if_needed, 
+        seeds = [
+            VERIFIED_SEED.as_bytes(), 
+            wallet.as_ref()
+        ], 
+        payer = verifier, 
+        bump, 
+        space = Verified::LEN + 8,
+    )]
+    pub verified: Account<'info, Verified>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -1,11 +1,97 @@
 pub use start_contract::*;
+pub use start_recurring_contract::*;
+pub use start_stream_contract::*;
+pub use start_team_contract::*;
+pub use post_job::*;
+pub use apply_job::*;
+pub use withdraw_application::*;
+pub use award_job::*;
 pub use activate_contract::*;
+pub use submit_delivery::*;
+pub use auto_release::*;
+pub use release_period::*;
+pub use object_period::*;
+pub use withdraw_vested::*;
+pub use stop_stream::*;
 pub use buyer_approve::*;
 pub use seller_approve::*;
 pub use admin_approve::*;
+pub use initialize_config::*;
+pub use withdraw_treasury::*;
+pub use propose_admin::*;
+pub use accept_admin::*;
+pub use set_paused::*;
+pub use cancel_contract::*;
+pub use increase_amount::*;
+pub use propose_amendment::*;
+pub use accept_amendment::*;
+pub use cancel_amendment::*;
+pub use counter_split::*;
+pub use accept_split::*;
+pub use submit_review::*;
+pub use set_verifier::*;
+pub use issue_verification::*;
+pub use revoke_verification::*;
+pub use ban_wallet::*;
+pub use unban_wallet::*;
+pub use set_appeal_config::*;
+pub use appeal_decision::*;
+pub use resolve_appeal::*;
+pub use execute_resolution::*;
+pub use veto_resolution::*;
+pub use admin_approve_many::*;
+pub use propose_treasury_authority::*;
+pub use accept_treasury_authority::*;
+pub use expire_appeal::*;
+pub use set_guardian::*;
+pub use execute_resolution_many::*;
+pub use set_max_arbiter_fee::*;
 
 pub mod start_contract;
 pub mod activate_contract;
 pub mod buyer_approve;
 pub mod seller_approve;
-pub mod admin_approve;
\ No newline at end of file
+pub mod admin_approve;
+pub mod initialize_config;
+pub mod withdraw_treasury;
+pub mod propose_admin;
+pub mod accept_admin;
+pub mod set_paused;
+pub mod cancel_contract;
+pub mod increase_amount;
+pub mod propose_amendment;
+pub mod accept_amendment;
+pub mod cancel_amendment;
+pub mod counter_split;
+pub mod accept_split;
+pub mod submit_delivery;
+pub mod auto_release;
+pub mod start_recurring_contract;
+pub mod release_period;
+pub mod object_period;
+pub mod start_stream_contract;
+pub mod withdraw_vested;
+pub mod stop_stream;
+pub mod start_team_contract;
+pub mod post_job;
+pub mod apply_job;
+pub mod withdraw_application;
+pub mod award_job;
+pub mod submit_review;
+pub mod set_verifier;
+pub mod issue_verification;
+pub mod revoke_verification;
+pub mod ban_wallet;
+pub mod unban_wallet;
+pub mod set_appeal_config;
+pub mod appeal_decision;
+pub mod resolve_appeal;
+pub mod execute_resolution;
+pub mod veto_resolution;
+pub mod admin_approve_many;
+pub mod propose_treasury_authority;
+pub mod accept_treasury_authority;
+pub mod expire_appeal;
+pub mod set_guardian;
+pub mod execute_resolution_many;
+pub mod set_max_arbiter_fee;
\ No newline at end of file
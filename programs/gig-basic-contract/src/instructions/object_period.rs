@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::state::contract::*;
+use crate::constants::{
+    CONTRACT_SEED,
+    BPS_DENOMINATOR
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn object_period(
+    ctx: Context<ObjectPeriodContext>,
+    _contract_id: String,
+) -> Result<()> {
+    msg!("Objecting to next period on buyer side!");
+
+    let contract = &mut ctx.accounts.contract;
+
+    // Check if the signer is a correct buyer
+    require_keys_eq!(ctx.accounts.buyer.key(), contract.buyer, GigContractError::InvalidBuyer);
+
+    require!(contract.kind == ContractKind::Recurring, GigContractError::NotRecurring);
+    require!(contract.status == ContractStatus::Active, GigContractError::CantRelease);
+
+    // Admin decides on the period that was due, split decision is half and half.
+    // Contract ends with the decision and later periods are refunded to buyer
+    contract.status = ContractStatus::Dispute;
+    contract.dispute_raiser = contract.buyer;
+    contract.buyer_approved = false;
+    contract.seller_share_bps = BPS_DENOMINATOR / 2;
+    contract.buyer_proposal_bps = BPS_DENOMINATOR / 2;
+
+    msg!("Objection raised successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(contract_id: String)]
+pub struct ObjectPeriodContext<'info> {
+    pub buyer: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds = [
+            CONTRACT_SEED.as_bytes(), 
+            contract.buyer.as_ref(),
+            contract_id.as_bytes()
+        ], 
+        bump, 
+    )]
+    pub contract: Account<'info, Contract>,
+}
@@ -0,0 +1,181 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{ self, Mint, Token, TokenAccount, Transfer as SplTransfer }
+};
+
+use crate::state::contract::*;
+use crate::state::config::*;
+use crate::state::profile::*;
+use crate::constants::{
+    CONTRACT_SEED,
+    CONFIG_SEED,
+    PROFILE_SEED,
+    PROFILE_PAGE_SEED,
+    BANNED_SEED,
+    PAUSE_NEW_CONTRACTS,
+    PAY_TOKEN_MINT_ADDRESS,
+    MIN_REVIEW_PERIOD
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+#[allow(clippy::too_many_arguments)]
+pub fn post_job(
+    ctx: Context<PostJobContext>,
+    contract_id: String,
+    amount: u64, 
+    dispute: u64, // $0.5 for now
+    deadline: u32,
+    review_period: u32,
+    bid_deadline: u32, // after this the lowest bid can be awarded by anyone
+    require_verified: bool, // seller has to hold a valid verification to activate
+) -> Result<()> {
+    msg!("Posting a new job with the following Id: {}", contract_id);
+
+    require_keys_eq!(ctx.accounts.pay_token_mint.key(), PAY_TOKEN_MINT_ADDRESS, GigContractError::PayTokenMintError);
+
+    // Check if creating new contracts is paused
+    require!(!ctx.accounts.config.is_paused(PAUSE_NEW_CONTRACTS), GigContractError::NewContractsPaused);
+
+    // Check if the buyer is banned
+    require!(ctx.accounts.buyer_ban.data_is_empty(), GigContractError::WalletBanned);
+
+    // powi(10.0, 6) for USDC, powi(10.0, 8) for BPT for test
+    require!(dispute == (0.5 * f64::powi(10.0, 8)).round() as u64 , GigContractError::InvalidDisputeAmount);
+
+    // Buyer needs time to review a delivery before the seller can be paid automatically
+    require!(review_period >= MIN_REVIEW_PERIOD, GigContractError::InvalidReviewPeriod);
+    require!(amount > 0, GigContractError::InvalidAmount);
+
+    // Bidding has to stay open for a while, otherwise the first applicant could award itself right away
+    let current_timestamp = Clock::get()?.unix_timestamp as u32;
+    require!(current_timestamp < bid_deadline && bid_deadline <= deadline, GigContractError::InvalidDeadline);
+
+    let contract = &mut ctx.accounts.contract;
+    let token_program = &ctx.accounts.token_program;
+    let authority = &ctx.accounts.buyer;
+    let source = &ctx.accounts.buyer_ata;
+    let destination = &ctx.accounts.contract_ata;
+
+    // Seller is set when buyer awards the job
+    contract.contract_id = contract_id;
+    contract.buyer = ctx.accounts.buyer.key();
+    contract.seller = Pubkey::default();
+    contract.start_time = current_timestamp;
+    contract.amount = amount;
+    contract.dispute = dispute;
+    contract.deadline = deadline;
+    contract.review_period = review_period;
+    contract.bid_deadline = bid_deadline;
+    contract.require_verified = require_verified;
+    contract.status = ContractStatus::Open;
+
+    // Transfer paytoken(amount + dispute) to the contract account, amount is the ceiling price
+    token::transfer(
+    CpiContext::new(
+        token_program.to_account_info(),
+        SplTransfer {
+            from: source.to_account_info().clone(),
+            to: destination.to_account_info().clone(),
+            authority: authority.to_account_info().clone(),
+        },
+    ),
+    amount + dispute,
+    )?;
+
+    // Index the contract in buyer's profile
+    let contract_key = ctx.accounts.contract.key();
+    ctx.accounts.buyer_profile.add_contract(&mut ctx.accounts.buyer_page, authority.key(), contract_key, true, ctx.bumps.buyer_profile, ctx.bumps.buyer_page);
+
+    msg!("New job posted successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(contract_id: String)]
+pub struct PostJobContext<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(
+        init, 
+        seeds = [
+            CONTRACT_SEED.as_bytes(), 
+            buyer.key().as_ref(),
+            contract_id.as_bytes()
+        ], 
+        payer = buyer, 
+        bump, 
+        space = Contract::LEN + 8,
+    )]
+    pub contract: Account<'info, Contract>,
+
+    #[account(
+        init_if_needed, 
+        seeds = [
+            PROFILE_SEED.as_bytes(), 
+            buyer.key().as_ref()
+        ], 
+        payer = buyer, 
+        bump, 
+        space = size_of::<UserProfile>() + 8,
+    )]
+    pub buyer_profile: Account<'info, UserProfile>,
+
+    // Current page of buyer's contract list, a new one is created every CONTRACT_PAGE_SIZE contracts
+    #[account(
+        init_if_needed, 
+        seeds = [
+            PROFILE_PAGE_SEED.as_bytes(), 
+            buyer.key().as_ref(),
+            &buyer_profile.page_index().to_le_bytes()
+        ], 
+        payer = buyer, 
+        bump, 
+        space = size_of::<ContractPage>() + 8,
+    )]
+    pub buyer_page: Account<'info, ContractPage>,
+
+    /// CHECK: Banned PDA of buyer, has to be empty unless the wallet is banned
+    #[account(
+        seeds = [
+            BANNED_SEED.as_bytes(), 
+            buyer.key().as_ref()
+        ], 
+        bump, 
+    )]
+    pub buyer_ban: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
+    )]
+    pub config: Account<'info, Config>,
+
+    pub pay_token_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut, 
+        associated_token::mint = pay_token_mint,
+        associated_token::authority = buyer,
+    )]
+    pub buyer_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = pay_token_mint,
+        associated_token::authority = contract,
+    )]
+    pub contract_ata: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>
+}
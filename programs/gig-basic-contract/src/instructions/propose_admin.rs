@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::state::config::*;
+use crate::constants::{
+    CONFIG_SEED
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn propose_admin(
+    ctx: Context<ProposeAdminContext>,
+    new_admin: Pubkey,
+) -> Result<()> {
+    msg!("Proposing a new admin: {}", new_admin);
+
+    let config = &mut ctx.accounts.config;
+
+    // Check if the signer is a correct admin
+    require_keys_eq!(ctx.accounts.admin.key(), config.admin, GigContractError::InvalidAdmin);
+
+    let current_timestamp = Clock::get()?.unix_timestamp as u32;
+
+    config.pending_admin = new_admin;
+    config.admin_transfer_ready_at = current_timestamp + config.admin_timelock;
+
+    msg!("New admin proposed successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeAdminContext<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
+    )]
+    pub config: Account<'info, Config>,
+}
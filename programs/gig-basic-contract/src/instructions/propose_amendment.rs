@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::state::contract::*;
+use crate::state::amendment::*;
+use crate::constants::{
+    CONTRACT_SEED,
+    AMENDMENT_SEED
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn propose_amendment(
+    ctx: Context<ProposeAmendmentContext>,
+    _contract_id: String,
+    deadline: u32,
+    amount: u64,
+) -> Result<()> {
+    msg!("Proposing amendment with deadline {} and amount {}", deadline, amount);
+
+    let contract = &ctx.accounts.contract;
+    let proposer = ctx.accounts.proposer.key();
+
+    // Check if the signer is one of the contract parties
+    require!(proposer == contract.buyer || proposer == contract.seller, GigContractError::InvalidProposer);
+
+    // Terms can only be changed before any party approved
+    require!(contract.status == ContractStatus::Created || contract.status == ContractStatus::Active, GigContractError::CantAmend);
+    require!(contract.kind == ContractKind::OneTime, GigContractError::CantAmend);
+    require!(amount > 0 && amount <= contract.amount, GigContractError::InvalidAmount);
+
+    let amendment = &mut ctx.accounts.amendment;
+
+    amendment.contract = contract.key();
+    amendment.proposer = proposer;
+    amendment.deadline = deadline;
+    amendment.amount = amount;
+    amendment.base_deadline = contract.deadline;
+    amendment.base_amount = contract.amount;
+    amendment.bump = ctx.bumps.amendment;
+
+    msg!("Amendment proposed successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(contract_id: String)]
+pub struct ProposeAmendmentContext<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            CONTRACT_SEED.as_bytes(), 
+            contract.buyer.as_ref(),
+            contract_id.as_bytes()
+        ], 
+        bump, 
+    )]
+    pub contract: Account<'info, Contract>,
+
+    #[account(
+        init, 
+        seeds = [
+            AMENDMENT_SEED.as_bytes(), 
+            contract.key().as_ref()
+        ], 
+        payer = proposer, 
+        bump, 
+        space = Amendment::LEN + 8,
+    )]
+    pub amendment: Account<'info, Amendment>,
+
+    pub system_program: Program<'info, System>,
+}
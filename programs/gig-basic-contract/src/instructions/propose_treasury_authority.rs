@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::state::config::*;
+use crate::constants::{
+    CONFIG_SEED
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn propose_treasury_authority(
+    ctx: Context<ProposeTreasuryAuthorityContext>,
+    new_treasury_authority: Pubkey,
+) -> Result<()> {
+    msg!("Proposing a new treasury authority: {}", new_treasury_authority);
+
+    let config = &mut ctx.accounts.config;
+
+    // Check if the signer is a correct treasury authority
+    require_keys_eq!(ctx.accounts.treasury_authority.key(), config.treasury_authority, GigContractError::InvalidTreasuryAuthority);
+
+    let current_timestamp = Clock::get()?.unix_timestamp as u32;
+
+    config.pending_treasury_authority = new_treasury_authority;
+    config.treasury_transfer_ready_at = current_timestamp + config.admin_timelock;
+
+    msg!("New treasury authority proposed successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeTreasuryAuthorityContext<'info> {
+    pub treasury_authority: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
+    )]
+    pub config: Account<'info, Config>,
+}
@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{ Token, TokenAccount }
+};
+
+use crate::state::contract::*;
+use crate::state::config::*;
+use crate::state::profile::*;
+use crate::events::ContractSettled;
+use crate::utils::{ transfer_from_contract, pay_sellers };
+use crate::constants::{
+    CONTRACT_SEED,
+    PROFILE_SEED,
+    CONFIG_SEED,
+    PAUSE_SETTLEMENTS,
+    PAY_TOKEN_MINT_ADDRESS
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn release_period<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReleasePeriodContext<'info>>,
+    _contract_id: String,
+) -> Result<()> {
+    msg!("Releasing next period of recurring contract!");
+
+    let contract = &mut ctx.accounts.contract;
+
+    // Check if settlements are paused
+    require!(!ctx.accounts.config.is_paused(PAUSE_SETTLEMENTS), GigContractError::SettlementsPaused);
+
+    require!(contract.kind == ContractKind::Recurring, GigContractError::NotRecurring);
+    require!(contract.status == ContractStatus::Active, GigContractError::CantRelease);
+
+    // Check if the next period is due, buyer can object before this time
+    let current_timestamp = Clock::get()?.unix_timestamp as u32;
+    require!(current_timestamp >= contract.next_release_at, GigContractError::PeriodNotDue);
+
+    let token_program = &ctx.accounts.token_program;
+    let source = &ctx.accounts.contract_ata;
+    let seller_destination = &ctx.accounts.seller_ata;
+    let buyer_destination = &ctx.accounts.buyer_ata;
+    let treasury_destination = &ctx.accounts.treasury_ata;
+
+    let payment = contract.period_payment();
+    let payout = contract.release_payout(payment, contract.is_last_period());
+
+    if contract.is_last_period() {
+        contract.status = ContractStatus::Completed;
+        let volume = contract.paid_volume(&payout);
+        ctx.accounts.buyer_profile.record_completion(volume);
+        ctx.accounts.seller_profile.record_completion(volume);
+    }
+
+    contract.released += payment;
+    contract.periods_released += 1;
+    contract.next_release_at += contract.period_interval;
+
+    pay_sellers(token_program, contract, source, seller_destination, ctx.remaining_accounts, ctx.bumps.contract, &payout)?;
+    emit!(ContractSettled::new(contract.key(), &payout, Pubkey::default()));
+    transfer_from_contract(token_program, contract, source, buyer_destination, ctx.bumps.contract, payout.buyer)?;
+    transfer_from_contract(token_program, contract, source, treasury_destination, ctx.bumps.contract, payout.treasury)?;
+
+    msg!("Period released successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(contract_id: String)]
+pub struct ReleasePeriodContext<'info> {
+    pub cranker: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds = [
+            CONTRACT_SEED.as_bytes(), 
+            contract.buyer.as_ref(),
+            contract_id.as_bytes()
+        ], 
+        bump, 
+    )]
+    pub contract: Account<'info, Contract>,
+
+    #[account(
+        mut, 
+        seeds = [
+            PROFILE_SEED.as_bytes(), 
+            contract.buyer.as_ref()
+        ], 
+        bump = buyer_profile.bump, 
+    )]
+    pub buyer_profile: Account<'info, UserProfile>,
+
+    // Lead seller's profile for team contracts
+    #[account(
+        mut, 
+        seeds = [
+            PROFILE_SEED.as_bytes(), 
+            contract.seller.as_ref()
+        ], 
+        bump = seller_profile.bump, 
+    )]
+    pub seller_profile: Account<'info, UserProfile>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = contract.seller,
+    )]
+    pub seller_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = contract.buyer,
+    )]
+    pub buyer_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = config,
+    )]
+    pub treasury_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = contract,
+    )]
+    pub contract_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{ Token, TokenAccount }
+};
+
+use crate::state::contract::*;
+use crate::state::config::*;
+use crate::utils::transfer_from_contract;
+use crate::constants::{
+    CONTRACT_SEED,
+    CONFIG_SEED,
+    PAUSE_SETTLEMENTS,
+    RESOLUTION_DELAY,
+    PAY_TOKEN_MINT_ADDRESS
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn resolve_appeal(
+    ctx: Context<ResolveAppealContext>,
+    _contract_id: String,
+    reverse: bool, // true if the appeal succeeds
+) -> Result<()> {
+    msg!("Resolving appeal, reverse: {}", reverse);
+
+    let contract = &mut ctx.accounts.contract;
+    let appeal_arbiter = ctx.accounts.appeal_arbiter.key();
+
+    // Check if the signer is one of the higher-tier arbiters
+    require!(ctx.accounts.config.is_appeal_arbiter(appeal_arbiter), GigContractError::InvalidAppealArbiter);
+
+    // Check if settlements are paused
+    require!(!ctx.accounts.config.is_paused(PAUSE_SETTLEMENTS), GigContractError::SettlementsPaused);
+
+    require!(contract.status == ContractStatus::Appealed, GigContractError::NotAppealed);
+
+    let token_program = &ctx.accounts.token_program;
+    let source = &ctx.accounts.contract_ata;
+    let appellant_destination = &ctx.accounts.appellant_ata;
+    let treasury_destination = &ctx.accounts.treasury_ata;
+
+    // Final decision can be executed by execute_resolution after the veto delay
+    let current_timestamp = Clock::get()?.unix_timestamp as u32;
+    contract.status = ContractStatus::Resolved;
+    contract.appeal_deadline = current_timestamp;
+    contract.resolution_ready_at = current_timestamp + RESOLUTION_DELAY;
+
+    if reverse {
+        // Decision is reversed in favor of the appellant and the bond is returned
+        contract.decision = if contract.appellant == contract.buyer { 2 } else { 1 };
+        contract.resolved_by = appeal_arbiter;
+
+        transfer_from_contract(token_program, contract, source, appellant_destination, ctx.bumps.contract, contract.appeal_bond)?;
+    } else {
+        // Original decision stands and the bond is forfeited to treasury
+        transfer_from_contract(token_program, contract, source, treasury_destination, ctx.bumps.contract, contract.appeal_bond)?;
+    }
+
+    msg!("Appeal resolved successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(contract_id: String)]
+pub struct ResolveAppealContext<'info> {
+    pub appeal_arbiter: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds = [
+            CONTRACT_SEED.as_bytes(), 
+            contract.buyer.as_ref(),
+            contract_id.as_bytes()
+        ], 
+        bump, 
+    )]
+    pub contract: Account<'info, Contract>,
+
+    #[account(
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = contract.appellant,
+    )]
+    pub appellant_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = config,
+    )]
+    pub treasury_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = contract,
+    )]
+    pub contract_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::state::config::*;
+use crate::state::verified::*;
+use crate::constants::{
+    CONFIG_SEED,
+    VERIFIED_SEED
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn revoke_verification(
+    ctx: Context<RevokeVerificationContext>,
+) -> Result<()> {
+    msg!("Revoking verification for {}", ctx.accounts.verified.wallet);
+
+    // Check if the signer is a correct verifier
+    require_keys_eq!(ctx.accounts.verifier.key(), ctx.accounts.config.verifier, GigContractError::InvalidVerifier);
+
+    // Verified account is closed and its rent goes back to verifier
+    msg!("Verification revoked successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevokeVerificationContext<'info> {
+    #[account(mut)]
+    pub verifier: Signer<'info>,
+
+    #[account(
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut, 
+        seeds = [
+            VERIFIED_SEED.as_bytes(), 
+            verified.wallet.as_ref()
+        ], 
+        bump = verified.bump, 
+        close = verifier,
+    )]
+    pub verified: Account<'info, Verified>,
+}
@@ -1,14 +1,21 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{ self, Mint, Token, TokenAccount, Transfer as SplTransfer }
+    token::{ Mint, Token, TokenAccount }
 };
 use std::mem::size_of;
 
 use crate::state::contract::*;
+use crate::state::config::*;
+use crate::state::profile::*;
+use crate::events::ContractSettled;
+use crate::utils::{ transfer_from_contract, pay_sellers };
 use crate::constants::{
     CONTRACT_SEED,
-    ADMIN_ADDRESS,
+    PROFILE_SEED,
+    CONFIG_SEED,
+    PAUSE_SETTLEMENTS,
+    BPS_DENOMINATOR,
     PAY_TOKEN_MINT_ADDRESS
 };
 use crate::errors::{
@@ -16,9 +23,9 @@ use crate::errors::{
 };
 
 
-pub fn seller_approve(
-    ctx: Context<SellerApproveContext>,
-    contract_id: String,
+pub fn seller_approve<'info>(
+    ctx: Context<'_, '_, '_, 'info, SellerApproveContext<'info>>,
+    _contract_id: String,
     seller_satisfied: bool
 ) -> Result<()> {
     msg!("Releasing funds on seller side!");
@@ -28,118 +35,52 @@ pub fn seller_approve(
     // Check if the signer is a correct seller
     require_keys_eq!(ctx.accounts.seller.key(), contract.seller, GigContractError::InvalidSeller);
 
-    // Check if the contract is Active or pending.
-    require!(contract.status == ContractStatus::Active || contract.status == ContractStatus::Pending, GigContractError::CantRelease);
+    // Check if settlements are paused
+    require!(!ctx.accounts.config.is_paused(PAUSE_SETTLEMENTS), GigContractError::SettlementsPaused);
+
+    // Check if the contract is Active, Delivered or pending.
+    require!(contract.status == ContractStatus::Active || contract.status == ContractStatus::Delivered || contract.status == ContractStatus::Pending, GigContractError::CantRelease);
+
+    // Recurring and streamed contracts are paid out per period or as vested, never released at once
+    require!(contract.kind == ContractKind::OneTime, GigContractError::CantRelease);
 
     let token_program = &ctx.accounts.token_program;
     let source = &ctx.accounts.contract_ata;
     let seller_destination = &ctx.accounts.seller_ata;
     let buyer_destination = &ctx.accounts.buyer_ata;
-    let admin_destination = &ctx.accounts.admin_ata;
+    let treasury_destination = &ctx.accounts.treasury_ata;
 
     contract.status = ContractStatus::Pending;
     contract.seller_approved = true;
+    contract.seller_satisfied = seller_satisfied;
 
-    let total_balance = source.amount;
-
-    // If both parties approve, transfer funds from the contrac to seller
-    // dispute for both party and platform fee to admin
-    if contract.buyer_approved == true {
-        if contract.split == true {
-            if seller_satisfied == true {
-                // if both parties agress with split decision, then split payment
-                contract.status = ContractStatus::Completed;
-
-                // To seller
-                token::transfer(
-                CpiContext::new_with_signer(
-                    token_program.to_account_info(),
-                    SplTransfer {
-                        from: source.to_account_info().clone(),
-                        to: seller_destination.to_account_info().clone(),
-                        authority: contract.to_account_info().clone(),
-                    },
-                    &[&[CONTRACT_SEED.as_bytes(), &contract.contract_id.as_bytes(), &[ctx.bumps.contract]]],
-                ),
-                ((total_balance - 2 * contract.dispute) * 45 / 100 + contract.dispute).try_into().unwrap(),
-                )?;
-
-                // To buyer
-                token::transfer(
-                CpiContext::new_with_signer(
-                    token_program.to_account_info(),
-                    SplTransfer {
-                        from: source.to_account_info().clone(),
-                        to: buyer_destination.to_account_info().clone(),
-                        authority: contract.to_account_info().clone(),
-                    },
-                    &[&[CONTRACT_SEED.as_bytes(), &contract.contract_id.as_bytes(), &[ctx.bumps.contract]]],
-                ),
-                ((total_balance - 2 * contract.dispute) * 45 / 100 + contract.dispute).try_into().unwrap(),
-                )?;
-
-                // To admin
-                token::transfer(
-                CpiContext::new_with_signer(
-                    token_program.to_account_info(),
-                    SplTransfer {
-                        from: source.to_account_info().clone(),
-                        to: admin_destination.to_account_info().clone(),
-                        authority: contract.to_account_info().clone(),
-                    },
-                    &[&[CONTRACT_SEED.as_bytes(), &contract.contract_id.as_bytes(), &[ctx.bumps.contract]]],
-                ),
-                ((total_balance - 2 * contract.dispute ) * 10 / 100).try_into().unwrap(),
-                )?;
-            } else {
-                // Raise dispute if seller is not satisfied with split decision
-                contract.status = ContractStatus::Dispute;
-            }
-        } else {
-            // When both parties are satisfied with the result
+    // If both parties approve, transfer funds from the contract to seller and buyer
+    // by the share buyer proposed, dispute back to both party and platform fee to treasury
+    if contract.buyer_approved {
+        // Seller can't accept the counter-offer it made itself, buyer has to answer it
+        if contract.is_split_negotiating() && seller_satisfied {
+            require_keys_neq!(contract.split_proposer, contract.seller, GigContractError::SplitAwaitingCounterparty);
+
+            let current_timestamp = Clock::get()?.unix_timestamp as u32;
+            require!(!contract.is_split_expired(current_timestamp), GigContractError::SplitExpired);
+        }
+
+        if contract.seller_share_bps == BPS_DENOMINATOR || seller_satisfied {
             contract.status = ContractStatus::Completed;
 
-            // To seller
-            token::transfer(
-            CpiContext::new_with_signer(
-                token_program.to_account_info(),
-                SplTransfer {
-                    from: source.to_account_info().clone(),
-                    to: seller_destination.to_account_info().clone(),
-                    authority: contract.to_account_info().clone(),
-                },
-                &[&[CONTRACT_SEED.as_bytes(), &contract.contract_id.as_bytes(), &[ctx.bumps.contract]]],
-            ),
-            ((total_balance - 2 * contract.dispute) * 90 / 100 + contract.dispute).try_into().unwrap(),
-            )?;
-
-            // To buyer
-            token::transfer(
-            CpiContext::new_with_signer(
-                token_program.to_account_info(),
-                SplTransfer {
-                    from: source.to_account_info().clone(),
-                    to: buyer_destination.to_account_info().clone(),
-                    authority: contract.to_account_info().clone(),
-                },
-                &[&[CONTRACT_SEED.as_bytes(), &contract.contract_id.as_bytes(), &[ctx.bumps.contract]]],
-            ),
-            contract.dispute,
-            )?;
-
-            // To admin
-            token::transfer(
-            CpiContext::new_with_signer(
-                token_program.to_account_info(),
-                SplTransfer {
-                    from: source.to_account_info().clone(),
-                    to: admin_destination.to_account_info().clone(),
-                    authority: contract.to_account_info().clone(),
-                },
-                &[&[CONTRACT_SEED.as_bytes(), &contract.contract_id.as_bytes(), &[ctx.bumps.contract]]],
-            ),
-            ((total_balance - 2 * contract.dispute ) * 10 / 100).try_into().unwrap(),
-            )?;
+            let payout = contract.payout(contract.seller_share_bps, contract.dispute, contract.dispute);
+            let volume = contract.paid_volume(&payout);
+            ctx.accounts.buyer_profile.record_completion(volume);
+            ctx.accounts.seller_profile.record_completion(volume);
+
+            pay_sellers(token_program, contract, source, seller_destination, ctx.remaining_accounts, ctx.bumps.contract, &payout)?;
+            emit!(ContractSettled::new(contract.key(), &payout, Pubkey::default()));
+            transfer_from_contract(token_program, contract, source, buyer_destination, ctx.bumps.contract, payout.buyer)?;
+            transfer_from_contract(token_program, contract, source, treasury_destination, ctx.bumps.contract, payout.treasury)?;
+        } else {
+            // Raise dispute if seller rejects split decision outright instead of countering it
+            contract.status = ContractStatus::Dispute;
+            contract.dispute_raiser = contract.seller;
         }
     }
 
@@ -157,12 +98,34 @@ pub struct SellerApproveContext<'info> {
         mut, 
         seeds = [
             CONTRACT_SEED.as_bytes(), 
+            contract.buyer.as_ref(),
             &contract_id.as_bytes()
         ], 
         bump, 
     )]
     pub contract: Account<'info, Contract>,
 
+    #[account(
+        mut, 
+        seeds = [
+            PROFILE_SEED.as_bytes(), 
+            contract.buyer.as_ref()
+        ], 
+        bump = buyer_profile.bump, 
+    )]
+    pub buyer_profile: Account<'info, UserProfile>,
+
+    // Lead seller's profile for team contracts
+    #[account(
+        mut, 
+        seeds = [
+            PROFILE_SEED.as_bytes(), 
+            contract.seller.as_ref()
+        ], 
+        bump = seller_profile.bump, 
+    )]
+    pub seller_profile: Account<'info, UserProfile>,
+
     #[account(
         mut, 
         associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
@@ -177,12 +140,20 @@ pub struct SellerApproveContext<'info> {
     )]
     pub buyer_ata: Account<'info, TokenAccount>,
 
+    #[account(
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
+    )]
+    pub config: Account<'info, Config>,
+
     #[account(
         mut, 
         associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
-        associated_token::authority = ADMIN_ADDRESS,
+        associated_token::authority = config,
     )]
-    pub admin_ata: Account<'info, TokenAccount>,
+    pub treasury_ata: Account<'info, TokenAccount>,
 
 
     #[account(
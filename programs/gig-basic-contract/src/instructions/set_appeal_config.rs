@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::state::config::*;
+use crate::constants::{
+    CONFIG_SEED,
+    MAX_APPEAL_ARBITERS
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn set_appeal_config(
+    ctx: Context<SetAppealConfigContext>,
+    appeal_window: u32, // 0 disables appeals and dispute decisions are executed right away
+    appeal_bond: u64,
+    appeal_arbiters: Vec<Pubkey>,
+) -> Result<()> {
+    msg!("Setting appeal window to {} seconds", appeal_window);
+
+    let config = &mut ctx.accounts.config;
+
+    // Check if the signer is a correct admin
+    require_keys_eq!(ctx.accounts.admin.key(), config.admin, GigContractError::InvalidAdmin);
+
+    // Appeals opened before may still be waiting, so there has to be an arbiter even when appeals are disabled
+    require!(appeal_arbiters.len() <= MAX_APPEAL_ARBITERS, GigContractError::InvalidAppealArbiter);
+    require!(!appeal_arbiters.is_empty(), GigContractError::InvalidAppealArbiter);
+
+    config.appeal_window = appeal_window;
+    config.appeal_bond = appeal_bond;
+    config.appeal_arbiters = [Pubkey::default(); MAX_APPEAL_ARBITERS];
+    config.appeal_arbiters[..appeal_arbiters.len()].copy_from_slice(&appeal_arbiters);
+
+    msg!("Appeal config updated successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetAppealConfigContext<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
+    )]
+    pub config: Account<'info, Config>,
+}
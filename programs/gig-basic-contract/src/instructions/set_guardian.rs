@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::state::config::*;
+use crate::constants::{
+    CONFIG_SEED
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn set_guardian(
+    ctx: Context<SetGuardianContext>,
+    guardian: Pubkey,
+) -> Result<()> {
+    msg!("Setting guardian to {}", guardian);
+
+    let config = &mut ctx.accounts.config;
+
+    // Check if the signer is the current guardian, the admin can't replace the key vetoing its decisions
+    require_keys_eq!(ctx.accounts.guardian.key(), config.guardian, GigContractError::InvalidGuardian);
+    require_keys_neq!(guardian, Pubkey::default(), GigContractError::InvalidGuardian);
+
+    config.guardian = guardian;
+
+    msg!("Guardian updated successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetGuardianContext<'info> {
+    pub guardian: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
+    )]
+    pub config: Account<'info, Config>,
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::state::config::*;
+use crate::constants::{
+    CONFIG_SEED,
+    BPS_DENOMINATOR
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn set_max_arbiter_fee(
+    ctx: Context<SetMaxArbiterFeeContext>,
+    max_arbiter_fee_bps: u16,
+) -> Result<()> {
+    msg!("Setting max arbiter fee to {} bps", max_arbiter_fee_bps);
+
+    let config = &mut ctx.accounts.config;
+
+    // Check if the signer is a correct admin
+    require_keys_eq!(ctx.accounts.admin.key(), config.admin, GigContractError::InvalidAdmin);
+
+    // Arbiter fee is a share of the platform fee
+    require!(max_arbiter_fee_bps <= BPS_DENOMINATOR, GigContractError::InvalidShare);
+
+    config.max_arbiter_fee_bps = max_arbiter_fee_bps;
+
+    msg!("Max arbiter fee updated successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetMaxArbiterFeeContext<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
+    )]
+    pub config: Account<'info, Config>,
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::state::config::*;
+use crate::constants::{
+    CONFIG_SEED
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn set_paused(
+    ctx: Context<SetPausedContext>,
+    paused: u8, // 1 for new contracts, 2 for settlements, 3 for both
+) -> Result<()> {
+    msg!("Setting pause flags: {}", paused);
+
+    let config = &mut ctx.accounts.config;
+
+    // Check if the signer is a correct guardian
+    require_keys_eq!(ctx.accounts.guardian.key(), config.guardian, GigContractError::InvalidGuardian);
+
+    config.paused = paused;
+
+    msg!("Pause flags updated successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPausedContext<'info> {
+    pub guardian: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
+    )]
+    pub config: Account<'info, Config>,
+}
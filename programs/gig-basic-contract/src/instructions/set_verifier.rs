@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::state::config::*;
+use crate::constants::{
+    CONFIG_SEED
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn set_verifier(
+    ctx: Context<SetVerifierContext>,
+    verifier: Pubkey,
+) -> Result<()> {
+    msg!("Setting verifier to {}", verifier);
+
+    let config = &mut ctx.accounts.config;
+
+    // Check if the signer is a correct admin
+    require_keys_eq!(ctx.accounts.admin.key(), config.admin, GigContractError::InvalidAdmin);
+
+    config.verifier = verifier;
+
+    msg!("Verifier updated successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetVerifierContext<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
+    )]
+    pub config: Account<'info, Config>,
+}
@@ -6,31 +6,58 @@ use anchor_spl::{
 use std::mem::size_of;
 
 use crate::state::contract::*;
+use crate::state::config::*;
+use crate::state::profile::*;
 use crate::constants::{
     CONTRACT_SEED,
+    CONFIG_SEED,
+    PROFILE_SEED,
+    PROFILE_PAGE_SEED,
+    BANNED_SEED,
+    PAUSE_NEW_CONTRACTS,
     PAY_TOKEN_MINT_ADDRESS,
-    DECIMAL
+    DECIMAL,
+    MIN_REVIEW_PERIOD
 };
 use crate::errors::{
     GigContractError
 };
 
 
+#[allow(clippy::too_many_arguments)]
 pub fn start_contract(
     ctx: Context<StartContractContext>,
     contract_id: String,
     amount: u64, 
     dispute: u64, // $0.5 for now
     deadline: u32,
+    review_period: u32, // seconds after delivery before seller can be paid automatically
+    require_verified: bool, // seller has to hold a valid verification to activate
+    arbiter: Option<Pubkey>, // resolves disputes instead of the platform admin
+    arbiter_fee_bps: u16, // share of the platform fee paid to the arbiter
 ) -> Result<()> {
     msg!("Creating a new contract with the following Id: {}", contract_id);
 
     require_keys_eq!(ctx.accounts.pay_token_mint.key(), PAY_TOKEN_MINT_ADDRESS, GigContractError::PayTokenMintError);
 
+    // Check if creating new contracts is paused
+    require!(!ctx.accounts.config.is_paused(PAUSE_NEW_CONTRACTS), GigContractError::NewContractsPaused);
+
+    // Check if any of the parties is banned
+    require!(ctx.accounts.buyer_ban.data_is_empty() && ctx.accounts.seller_ban.data_is_empty(), GigContractError::WalletBanned);
+
     // Check if the contract is pending which means one of two parties approved.
     // powi(10.0, 6) for USDC, powi(10.0, 8) for BPT for test
     require!(dispute == (0.5 * f64::powi(10.0, 8)).round() as u64 , GigContractError::InvalidDisputeAmount);
+
+    // Buyer needs time to review a delivery before the seller can be paid automatically
+    require!(review_period >= MIN_REVIEW_PERIOD, GigContractError::InvalidReviewPeriod);
     
+    // Arbiter has to be a third party
+    let arbiter = arbiter.unwrap_or_default();
+    require!(arbiter != ctx.accounts.buyer.key() && arbiter != ctx.accounts.seller.key(), GigContractError::InvalidArbiter);
+    require!(arbiter_fee_bps <= ctx.accounts.config.max_arbiter_fee_bps, GigContractError::InvalidShare);
+
     let contract = &mut ctx.accounts.contract;
     let current_timestamp = Clock::get()?.unix_timestamp as u32;
     let token_program = &ctx.accounts.token_program;
@@ -45,6 +72,10 @@ pub fn start_contract(
     contract.amount = amount;
     contract.dispute = dispute;
     contract.deadline = deadline;
+    contract.review_period = review_period;
+    contract.require_verified = require_verified;
+    contract.arbiter = arbiter;
+    contract.arbiter_fee_bps = arbiter_fee_bps;
     contract.status = ContractStatus::Created;
 
     // Transfer paytoken(amount + dispute) to the contract account
@@ -60,6 +91,10 @@ pub fn start_contract(
     (amount + dispute).try_into().unwrap(),
     )?;
   
+    // Index the contract in buyer's profile
+    let contract_key = ctx.accounts.contract.key();
+    ctx.accounts.buyer_profile.add_contract(&mut ctx.accounts.buyer_page, authority.key(), contract_key, true, ctx.bumps.buyer_profile, ctx.bumps.buyer_page);
+
     msg!("New contract created successfully!");
     Ok(())
 }
@@ -74,6 +109,7 @@ pub struct StartContractContext<'info> {
         init, 
         seeds = [
             CONTRACT_SEED.as_bytes(), 
+            buyer.key().as_ref(),
             &contract_id.as_bytes()
         ], 
         payer = buyer, 
@@ -82,8 +118,62 @@ pub struct StartContractContext<'info> {
     )]
     pub contract: Account<'info, Contract>,
 
+    #[account(
+        init_if_needed, 
+        seeds = [
+            PROFILE_SEED.as_bytes(), 
+            buyer.key().as_ref()
+        ], 
+        payer = buyer, 
+        bump, 
+        space = size_of::<UserProfile>() + 8,
+    )]
+    pub buyer_profile: Account<'info, UserProfile>,
+
+    // Current page of buyer's contract list, a new one is created every CONTRACT_PAGE_SIZE contracts
+    #[account(
+        init_if_needed, 
+        seeds = [
+            PROFILE_PAGE_SEED.as_bytes(), 
+            buyer.key().as_ref(),
+            &buyer_profile.page_index().to_le_bytes()
+        ], 
+        payer = buyer, 
+        bump, 
+        space = size_of::<ContractPage>() + 8,
+    )]
+    pub buyer_page: Account<'info, ContractPage>,
+
     pub seller: SystemAccount<'info>,
 
+    /// CHECK: Banned PDA of buyer, has to be empty unless the wallet is banned
+    #[account(
+        seeds = [
+            BANNED_SEED.as_bytes(), 
+            buyer.key().as_ref()
+        ], 
+        bump, 
+    )]
+    pub buyer_ban: UncheckedAccount<'info>,
+
+    /// CHECK: Banned PDA of seller, has to be empty unless the wallet is banned
+    #[account(
+        seeds = [
+            BANNED_SEED.as_bytes(), 
+            seller.key().as_ref()
+        ], 
+        bump, 
+    )]
+    pub seller_ban: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
+    )]
+    pub config: Account<'info, Config>,
+
     pub pay_token_mint: Account<'info, Mint>,
     
     #[account(
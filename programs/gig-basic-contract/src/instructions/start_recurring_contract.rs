@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{ self, Transfer as SplTransfer };
+
+use crate::state::contract::*;
+use crate::constants::{
+    PAUSE_NEW_CONTRACTS,
+    PAY_TOKEN_MINT_ADDRESS
+};
+use crate::errors::{
+    GigContractError
+};
+use super::start_contract::StartContractContext;
+
+
+pub fn start_recurring_contract(
+    ctx: Context<StartContractContext>,
+    contract_id: String,
+    amount_per_period: u64, 
+    dispute: u64, // $0.5 for now
+    period_count: u16,
+    period_interval: u32, // seconds between releases
+    require_verified: bool, // seller has to hold a valid verification to activate
+) -> Result<()> {
+    msg!("Creating a new recurring contract with the following Id: {}", contract_id);
+
+    require_keys_eq!(ctx.accounts.pay_token_mint.key(), PAY_TOKEN_MINT_ADDRESS, GigContractError::PayTokenMintError);
+
+    // Check if creating new contracts is paused
+    require!(!ctx.accounts.config.is_paused(PAUSE_NEW_CONTRACTS), GigContractError::NewContractsPaused);
+
+    // Check if any of the parties is banned
+    require!(ctx.accounts.buyer_ban.data_is_empty() && ctx.accounts.seller_ban.data_is_empty(), GigContractError::WalletBanned);
+
+    // powi(10.0, 6) for USDC, powi(10.0, 8) for BPT for test
+    require!(dispute == (0.5 * f64::powi(10.0, 8)).round() as u64 , GigContractError::InvalidDisputeAmount);
+    require!(period_count > 0 && period_interval > 0, GigContractError::InvalidPeriod);
+    require!(amount_per_period > 0, GigContractError::InvalidAmount);
+
+    let contract = &mut ctx.accounts.contract;
+    let current_timestamp = Clock::get()?.unix_timestamp as u32;
+    let token_program = &ctx.accounts.token_program;
+    let authority = &ctx.accounts.buyer;
+    let source = &ctx.accounts.buyer_ata;
+    let destination = &ctx.accounts.contract_ata;
+
+    // All periods are escrowed up front
+    let amount = amount_per_period.checked_mul(period_count as u64).ok_or(GigContractError::InvalidAmount)?;
+    let escrow = amount.checked_add(dispute).ok_or(GigContractError::InvalidAmount)?;
+    let deadline = period_interval
+        .checked_mul(period_count as u32)
+        .and_then(|duration| duration.checked_add(current_timestamp))
+        .ok_or(GigContractError::InvalidPeriod)?;
+
+    contract.contract_id = contract_id;
+    contract.buyer = ctx.accounts.buyer.key();
+    contract.seller = ctx.accounts.seller.key();
+    contract.start_time = current_timestamp;
+    contract.amount = amount;
+    contract.dispute = dispute;
+    contract.deadline = deadline;
+    contract.kind = ContractKind::Recurring;
+    contract.period_count = period_count;
+    contract.period_interval = period_interval;
+    contract.require_verified = require_verified;
+    contract.status = ContractStatus::Created;
+
+    // Transfer paytoken(amount + dispute) to the contract account
+    token::transfer(
+    CpiContext::new(
+        token_program.to_account_info(),
+        SplTransfer {
+            from: source.to_account_info().clone(),
+            to: destination.to_account_info().clone(),
+            authority: authority.to_account_info().clone(),
+        },
+    ),
+    escrow,
+    )?;
+
+    // Index the contract in buyer's profile
+    let contract_key = ctx.accounts.contract.key();
+    ctx.accounts.buyer_profile.add_contract(&mut ctx.accounts.buyer_page, authority.key(), contract_key, true, ctx.bumps.buyer_profile, ctx.bumps.buyer_page);
+
+    msg!("New recurring contract created successfully!");
+    Ok(())
+}
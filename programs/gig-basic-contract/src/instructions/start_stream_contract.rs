@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{ self, Transfer as SplTransfer };
+
+use crate::state::contract::*;
+use crate::constants::{
+    PAUSE_NEW_CONTRACTS,
+    PAY_TOKEN_MINT_ADDRESS
+};
+use crate::errors::{
+    GigContractError
+};
+use super::start_contract::StartContractContext;
+
+
+pub fn start_stream_contract(
+    ctx: Context<StartContractContext>,
+    contract_id: String,
+    amount: u64, 
+    dispute: u64, // $0.5 for now
+    deadline: u32, // amount is fully vested at this time
+    require_verified: bool, // seller has to hold a valid verification to activate
+) -> Result<()> {
+    msg!("Creating a new streamed contract with the following Id: {}", contract_id);
+
+    require_keys_eq!(ctx.accounts.pay_token_mint.key(), PAY_TOKEN_MINT_ADDRESS, GigContractError::PayTokenMintError);
+
+    // Check if creating new contracts is paused
+    require!(!ctx.accounts.config.is_paused(PAUSE_NEW_CONTRACTS), GigContractError::NewContractsPaused);
+
+    // Check if any of the parties is banned
+    require!(ctx.accounts.buyer_ban.data_is_empty() && ctx.accounts.seller_ban.data_is_empty(), GigContractError::WalletBanned);
+
+    // powi(10.0, 6) for USDC, powi(10.0, 8) for BPT for test
+    require!(dispute == (0.5 * f64::powi(10.0, 8)).round() as u64 , GigContractError::InvalidDisputeAmount);
+    require!(amount > 0, GigContractError::InvalidAmount);
+
+    let contract = &mut ctx.accounts.contract;
+    let current_timestamp = Clock::get()?.unix_timestamp as u32;
+    let token_program = &ctx.accounts.token_program;
+    let authority = &ctx.accounts.buyer;
+    let source = &ctx.accounts.buyer_ata;
+    let destination = &ctx.accounts.contract_ata;
+
+    require!(deadline > current_timestamp, GigContractError::InvalidDeadline);
+
+    contract.contract_id = contract_id;
+    contract.buyer = ctx.accounts.buyer.key();
+    contract.seller = ctx.accounts.seller.key();
+    contract.start_time = current_timestamp;
+    contract.amount = amount;
+    contract.dispute = dispute;
+    contract.deadline = deadline;
+    contract.kind = ContractKind::Stream;
+    contract.require_verified = require_verified;
+    contract.status = ContractStatus::Created;
+
+    // Transfer paytoken(amount + dispute) to the contract account
+    token::transfer(
+    CpiContext::new(
+        token_program.to_account_info(),
+        SplTransfer {
+            from: source.to_account_info().clone(),
+            to: destination.to_account_info().clone(),
+            authority: authority.to_account_info().clone(),
+        },
+    ),
+    amount + dispute,
+    )?;
+
+    // Index the contract in buyer's profile
+    let contract_key = ctx.accounts.contract.key();
+    ctx.accounts.buyer_profile.add_contract(&mut ctx.accounts.buyer_page, authority.key(), contract_key, true, ctx.bumps.buyer_profile, ctx.bumps.buyer_page);
+
+    msg!("New streamed contract created successfully!");
+    Ok(())
+}
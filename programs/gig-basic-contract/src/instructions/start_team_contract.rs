@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{ self, Transfer as SplTransfer };
+
+use crate::state::contract::*;
+use crate::constants::{
+    PAUSE_NEW_CONTRACTS,
+    PAY_TOKEN_MINT_ADDRESS,
+    BPS_DENOMINATOR,
+    MAX_TEAM_SIZE,
+    MIN_REVIEW_PERIOD
+};
+use crate::errors::{
+    GigContractError
+};
+use super::start_contract::StartContractContext;
+
+
+#[allow(clippy::too_many_arguments)]
+pub fn start_team_contract(
+    ctx: Context<StartContractContext>,
+    contract_id: String,
+    amount: u64, 
+    dispute: u64, // $0.5 for now, posted by every team member
+    deadline: u32,
+    review_period: u32,
+    team: Vec<TeamMember>, // lead seller first
+    require_verified: bool, // seller has to hold a valid verification to activate
+) -> Result<()> {
+    msg!("Creating a new team contract with the following Id: {}", contract_id);
+
+    require_keys_eq!(ctx.accounts.pay_token_mint.key(), PAY_TOKEN_MINT_ADDRESS, GigContractError::PayTokenMintError);
+
+    // Check if creating new contracts is paused
+    require!(!ctx.accounts.config.is_paused(PAUSE_NEW_CONTRACTS), GigContractError::NewContractsPaused);
+
+    // Check if any of the parties is banned
+    require!(ctx.accounts.buyer_ban.data_is_empty() && ctx.accounts.seller_ban.data_is_empty(), GigContractError::WalletBanned);
+
+    // powi(10.0, 6) for USDC, powi(10.0, 8) for BPT for test
+    require!(dispute == (0.5 * f64::powi(10.0, 8)).round() as u64 , GigContractError::InvalidDisputeAmount);
+
+    // Buyer needs time to review a delivery before the seller can be paid automatically
+    require!(review_period >= MIN_REVIEW_PERIOD, GigContractError::InvalidReviewPeriod);
+
+    // Check if the team is valid and led by the seller account
+    require!(team.len() >= 2 && team.len() <= MAX_TEAM_SIZE, GigContractError::InvalidTeam);
+    require_keys_eq!(team[0].seller, ctx.accounts.seller.key(), GigContractError::InvalidTeam);
+    require!(team.iter().map(|member| member.share_bps as u64).sum::<u64>() == BPS_DENOMINATOR as u64, GigContractError::InvalidTeam);
+    for (index, member) in team.iter().enumerate() {
+        require!(team[..index].iter().all(|other| other.seller != member.seller), GigContractError::InvalidTeam);
+    }
+
+    let contract = &mut ctx.accounts.contract;
+    let current_timestamp = Clock::get()?.unix_timestamp as u32;
+    let token_program = &ctx.accounts.token_program;
+    let authority = &ctx.accounts.buyer;
+    let source = &ctx.accounts.buyer_ata;
+    let destination = &ctx.accounts.contract_ata;
+
+    contract.contract_id = contract_id;
+    contract.buyer = ctx.accounts.buyer.key();
+    contract.seller = ctx.accounts.seller.key();
+    contract.start_time = current_timestamp;
+    contract.amount = amount;
+    contract.dispute = dispute;
+    contract.deadline = deadline;
+    contract.review_period = review_period;
+    contract.require_verified = require_verified;
+    contract.status = ContractStatus::Created;
+
+    for (index, member) in team.iter().enumerate() {
+        contract.team[index] = TeamMember {
+            seller: member.seller,
+            share_bps: member.share_bps,
+            activated: false,
+        };
+    }
+    contract.team_size = team.len() as u8;
+
+    // Transfer paytoken(amount + dispute) to the contract account
+    token::transfer(
+    CpiContext::new(
+        token_program.to_account_info(),
+        SplTransfer {
+            from: source.to_account_info().clone(),
+            to: destination.to_account_info().clone(),
+            authority: authority.to_account_info().clone(),
+        },
+    ),
+    amount + dispute,
+    )?;
+
+    // Index the contract in buyer's profile
+    let contract_key = ctx.accounts.contract.key();
+    ctx.accounts.buyer_profile.add_contract(&mut ctx.accounts.buyer_page, authority.key(), contract_key, true, ctx.bumps.buyer_profile, ctx.bumps.buyer_page);
+
+    msg!("New team contract created successfully!");
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::state::contract::*;
+use crate::constants::{
+    CONTRACT_SEED
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn submit_delivery(
+    ctx: Context<SubmitDeliveryContext>,
+    _contract_id: String,
+    delivery_hash: [u8; 32],
+) -> Result<()> {
+    msg!("Submitting delivery on seller side!");
+
+    let contract = &mut ctx.accounts.contract;
+
+    // Check if the signer is a correct seller
+    require_keys_eq!(ctx.accounts.seller.key(), contract.seller, GigContractError::InvalidSeller);
+
+    // Check if the contract is active, recurring contracts are paid by period instead
+    require!(contract.status == ContractStatus::Active && contract.kind == ContractKind::OneTime, GigContractError::CantDeliver);
+
+    let current_timestamp = Clock::get()?.unix_timestamp as u32;
+
+    contract.delivery_hash = delivery_hash;
+    contract.delivered_at = current_timestamp;
+    contract.status = ContractStatus::Delivered;
+
+    msg!("Delivery submitted successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(contract_id: String)]
+pub struct SubmitDeliveryContext<'info> {
+    pub seller: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds = [
+            CONTRACT_SEED.as_bytes(), 
+            contract.buyer.as_ref(),
+            contract_id.as_bytes()
+        ], 
+        bump, 
+    )]
+    pub contract: Account<'info, Contract>,
+}
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+use crate::state::contract::*;
+use crate::state::profile::*;
+use crate::state::review::*;
+use crate::constants::{
+    CONTRACT_SEED,
+    PROFILE_SEED,
+    REVIEW_SEED,
+    MAX_RATING
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn submit_review(
+    ctx: Context<SubmitReviewContext>,
+    _contract_id: String,
+    rating: u8,
+    content_hash: [u8; 32],
+) -> Result<()> {
+    msg!("Submitting review with rating {}", rating);
+
+    let contract = &ctx.accounts.contract;
+    let reviewer = ctx.accounts.reviewer.key();
+
+    // Check if the contract is completed
+    require!(contract.status == ContractStatus::Completed, GigContractError::CantReview);
+    require!((1..=MAX_RATING).contains(&rating), GigContractError::InvalidRating);
+
+    // Check if the signer is one of the contract parties, the other one is reviewed
+    let reviewee = if reviewer == contract.buyer {
+        contract.seller
+    } else if reviewer == contract.seller {
+        contract.buyer
+    } else {
+        return err!(GigContractError::InvalidReviewer);
+    };
+    require_keys_eq!(ctx.accounts.reviewee_profile.wallet, reviewee, GigContractError::InvalidReviewer);
+
+    let review = &mut ctx.accounts.review;
+
+    review.contract = contract.key();
+    review.reviewer = reviewer;
+    review.reviewee = reviewee;
+    review.rating = rating;
+    review.content_hash = content_hash;
+    review.bump = ctx.bumps.review;
+
+    ctx.accounts.reviewee_profile.add_rating(rating);
+
+    msg!("Review submitted successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(contract_id: String)]
+pub struct SubmitReviewContext<'info> {
+    #[account(mut)]
+    pub reviewer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            CONTRACT_SEED.as_bytes(), 
+            contract.buyer.as_ref(),
+            contract_id.as_bytes()
+        ], 
+        bump, 
+    )]
+    pub contract: Account<'info, Contract>,
+
+    // One review per party and contract
+    #[account(
+        init, 
+        seeds = [
+            REVIEW_SEED.as_bytes(), 
+            contract.key().as_ref(),
+            reviewer.key().as_ref()
+        ], 
+        payer = reviewer, 
+        bump, 
+        space = Review::LEN + 8,
+    )]
+    pub review: Account<'info, Review>,
+
+    #[account(
+        mut, 
+        seeds = [
+            PROFILE_SEED.as_bytes(), 
+            reviewee_profile.wallet.as_ref()
+        ], 
+        bump = reviewee_profile.bump, 
+    )]
+    pub reviewee_profile: Account<'info, UserProfile>,
+
+    pub system_program: Program<'info, System>,
+}
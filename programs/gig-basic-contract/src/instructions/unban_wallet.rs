@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::state::config::*;
+use crate::state::banned::*;
+use crate::constants::{
+    CONFIG_SEED,
+    BANNED_SEED
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn unban_wallet(
+    ctx: Context<UnbanWalletContext>,
+) -> Result<()> {
+    msg!("Unbanning wallet {}", ctx.accounts.banned.wallet);
+
+    // Check if the signer is a correct admin
+    require_keys_eq!(ctx.accounts.admin.key(), ctx.accounts.config.admin, GigContractError::InvalidAdmin);
+
+    // Banned account is closed and its rent goes back to admin
+    msg!("Wallet unbanned successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UnbanWalletContext<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut, 
+        seeds = [
+            BANNED_SEED.as_bytes(), 
+            banned.wallet.as_ref()
+        ], 
+        bump = banned.bump, 
+        close = admin,
+    )]
+    pub banned: Account<'info, Banned>,
+}
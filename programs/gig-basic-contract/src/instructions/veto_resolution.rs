@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::state::contract::*;
+use crate::state::config::*;
+use crate::constants::{
+    CONTRACT_SEED,
+    CONFIG_SEED
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn veto_resolution(
+    ctx: Context<VetoResolutionContext>,
+    _contract_id: String,
+) -> Result<()> {
+    msg!("Vetoing admin decision!");
+
+    let contract = &mut ctx.accounts.contract;
+
+    // Check if the signer is a correct guardian
+    require_keys_eq!(ctx.accounts.guardian.key(), ctx.accounts.config.guardian, GigContractError::InvalidGuardian);
+
+    // Check if the decision is recorded and not executed yet, appealed ones wait for the appeal arbiter
+    require!(contract.status == ContractStatus::Resolved, GigContractError::CantVeto);
+
+    // Contract has to be decided again
+    contract.status = if contract.disputed { ContractStatus::Dispute } else { ContractStatus::Pending };
+    contract.decision = 0;
+    contract.disputed = false;
+    contract.resolved_by = Pubkey::default();
+    contract.appeal_deadline = 0;
+    contract.appellant = Pubkey::default();
+    contract.appeal_bond = 0;
+    contract.appeal_resolve_by = 0;
+    contract.resolution_ready_at = 0;
+    contract.admin_approved = false;
+
+    msg!("Admin decision vetoed successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(contract_id: String)]
+pub struct VetoResolutionContext<'info> {
+    pub guardian: Signer<'info>,
+
+    #[account(
+        mut, 
+        seeds = [
+            CONTRACT_SEED.as_bytes(), 
+            contract.buyer.as_ref(),
+            contract_id.as_bytes()
+        ], 
+        bump, 
+    )]
+    pub contract: Account<'info, Contract>,
+
+    #[account(
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
+    )]
+    pub config: Account<'info, Config>,
+}
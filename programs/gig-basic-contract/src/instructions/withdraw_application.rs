@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::state::contract::*;
+use crate::state::application::*;
+use crate::constants::{
+    CONTRACT_SEED,
+    APPLICATION_SEED
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn withdraw_application(
+    ctx: Context<WithdrawApplicationContext>,
+    _contract_id: String,
+) -> Result<()> {
+    msg!("Withdrawing application on seller side!");
+
+    let contract = &ctx.accounts.contract;
+
+    // Lowest bid has to stay so it can still be awarded after bid deadline
+    require!(contract.status != ContractStatus::Open || contract.lowest_bidder != ctx.accounts.seller.key(), GigContractError::LowestBidLocked);
+
+    // Application account is closed and its rent goes back to seller
+
+    msg!("Application withdrawn successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(contract_id: String)]
+pub struct WithdrawApplicationContext<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    #[account(
+        seeds = [
+            CONTRACT_SEED.as_bytes(), 
+            contract.buyer.as_ref(),
+            contract_id.as_bytes()
+        ], 
+        bump, 
+    )]
+    pub contract: Account<'info, Contract>,
+
+    #[account(
+        mut, 
+        seeds = [
+            APPLICATION_SEED.as_bytes(), 
+            contract.key().as_ref(),
+            seller.key().as_ref()
+        ], 
+        bump = application.bump, 
+        close = seller,
+    )]
+    pub application: Account<'info, Application>,
+}
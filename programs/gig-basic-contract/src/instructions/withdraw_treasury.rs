@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{ self, Token, TokenAccount, Transfer as SplTransfer }
+};
+
+use crate::state::config::*;
+use crate::constants::{
+    CONFIG_SEED,
+    PAY_TOKEN_MINT_ADDRESS
+};
+use crate::errors::{
+    GigContractError
+};
+
+
+pub fn withdraw_treasury(
+    ctx: Context<WithdrawTreasuryContext>,
+    amount: u64,
+) -> Result<()> {
+    msg!("Withdrawing platform fees from treasury!");
+
+    let config = &ctx.accounts.config;
+
+    // Check if the signer is a correct treasury authority
+    require_keys_eq!(ctx.accounts.treasury_authority.key(), config.treasury_authority, GigContractError::InvalidTreasuryAuthority);
+
+    let token_program = &ctx.accounts.token_program;
+    let source = &ctx.accounts.treasury_ata;
+    let destination = &ctx.accounts.destination_ata;
+
+    require!(source.amount >= amount, GigContractError::InsufficientTreasuryBalance);
+
+    // Transfer paytoken from the treasury to the destination account
+    token::transfer(
+    CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        SplTransfer {
+            from: source.to_account_info().clone(),
+            to: destination.to_account_info().clone(),
+            authority: config.to_account_info().clone(),
+        },
+        &[&[CONFIG_SEED.as_bytes(), &[config.bump]]],
+    ),
+    amount,
+    )?;
+
+    msg!("Treasury withdrawn successfully!");
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTreasuryContext<'info> {
+    pub treasury_authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            CONFIG_SEED.as_bytes()
+        ], 
+        bump = config.bump, 
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut, 
+        associated_token::mint = PAY_TOKEN_MINT_ADDRESS,
+        associated_token::authority = config,
+    )]
+    pub treasury_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut, 
+        token::mint = PAY_TOKEN_MINT_ADDRESS,
+    )]
+    pub destination_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
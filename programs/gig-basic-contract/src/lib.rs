@@ -5,11 +5,14 @@ use anchor_spl::{
 };
 
 use instructions::*;
+use state::contract::TeamMember;
 
 pub mod instructions;
 pub mod constants;
 pub mod errors;
 pub mod state;
+pub mod utils;
+pub mod events;
 
 declare_id!("GuqgMVMCLi9daQMHyhnLRGTpgpwNGU4yZXs9GK4SYrbS");
 
@@ -20,31 +23,143 @@ pub mod gig_basic_contract {
     /* 
         Buyer will start a working contract between buyer and seller 
         by calling this function with payment amount and dispute fee. 
+        Contract address is derived from buyer and contract id, so clients look it up by (buyer, order id)
+        and nobody else can squat the same order id.
+        With require_verified only a seller holding a valid attestation can activate it.
+        An optional arbiter resolves disputes instead of the platform admin for arbiter_fee_bps of the platform fee,
+        capped by max_arbiter_fee_bps in config.
     */
-    
-    pub fn start_contract(ctx: Context<StartContractContext>, contract_id: String, amount: u64, dispute: u64, deadline: u32) -> Result<()> {
-        instructions::start_contract::start_contract(ctx, contract_id, amount, dispute, deadline)
+    #[allow(clippy::too_many_arguments)]
+    pub fn start_contract(ctx: Context<StartContractContext>, contract_id: String, amount: u64, dispute: u64, deadline: u32, review_period: u32, require_verified: bool, arbiter: Option<Pubkey>, arbiter_fee_bps: u16) -> Result<()> {
+        instructions::start_contract::start_contract(ctx, contract_id, amount, dispute, deadline, review_period, require_verified, arbiter, arbiter_fee_bps)
+    }
+
+    /* 
+        Buyer will start a recurring contract by escrowing period_count payments up front.
+        One period's payment is released to seller every period_interval unless buyer objects.
+    */
+    pub fn start_recurring_contract(ctx: Context<StartContractContext>, contract_id: String, amount_per_period: u64, dispute: u64, period_count: u16, period_interval: u32, require_verified: bool) -> Result<()> {
+        instructions::start_recurring_contract::start_recurring_contract(ctx, contract_id, amount_per_period, dispute, period_count, period_interval, require_verified)
+    }
+
+    /* 
+        Buyer will start a streamed contract, amount vests linearly to seller from now until deadline.
+    */
+    pub fn start_stream_contract(ctx: Context<StartContractContext>, contract_id: String, amount: u64, dispute: u64, deadline: u32, require_verified: bool) -> Result<()> {
+        instructions::start_stream_contract::start_stream_contract(ctx, contract_id, amount, dispute, deadline, require_verified)
+    }
+
+    /* 
+        Buyer will start a contract with a team of sellers sharing the seller payout by share_bps.
+        Every team member has to activate the contract posting its own dispute fee.
+        Completions and volume are recorded in the lead seller's profile only.
+    */
+    #[allow(clippy::too_many_arguments)]
+    pub fn start_team_contract(ctx: Context<StartContractContext>, contract_id: String, amount: u64, dispute: u64, deadline: u32, review_period: u32, team: Vec<TeamMember>, require_verified: bool) -> Result<()> {
+        instructions::start_team_contract::start_team_contract(ctx, contract_id, amount, dispute, deadline, review_period, team, require_verified)
+    }
+
+    /* 
+        Buyer will post an open job escrowing the ceiling price and dispute fee without choosing a seller.
+        Sellers apply with a bid and buyer awards the job to one of them.
+    */
+    #[allow(clippy::too_many_arguments)]
+    pub fn post_job(ctx: Context<PostJobContext>, contract_id: String, amount: u64, dispute: u64, deadline: u32, review_period: u32, bid_deadline: u32, require_verified: bool) -> Result<()> {
+        instructions::post_job::post_job(ctx, contract_id, amount, dispute, deadline, review_period, bid_deadline, require_verified)
+    }
+
+    /* 
+        Seller will apply to an open job with a bid not above the ceiling price.
+    */
+    pub fn apply_job(ctx: Context<ApplyJobContext>, contract_id: String, bid: u64) -> Result<()> {
+        instructions::apply_job::apply_job(ctx, contract_id, bid)
+    }
+
+    /* 
+        Seller will withdraw its application, or close it once the job is awarded to someone else.
+    */
+    pub fn withdraw_application(ctx: Context<WithdrawApplicationContext>, contract_id: String) -> Result<()> {
+        instructions::withdraw_application::withdraw_application(ctx, contract_id)
+    }
+
+    /* 
+        Buyer will award the open job to any applicant, or anyone will award it to the lowest bid after bid deadline.
+        Difference between the ceiling price and the bid is refunded to buyer 
+        and the job becomes a normal contract that the seller has to activate.
+    */
+    pub fn award_job(ctx: Context<AwardJobContext>, contract_id: String) -> Result<()> {
+        instructions::award_job::award_job(ctx, contract_id)
     }
 
     /* 
         Seller will activate the contract after checking all conditions that buyer set 
-        when creating the contract.
+        when creating the contract, including the arbiter.
+    */
+    pub fn activate_contract(ctx: Context<ActivateContractContext>, contract_id: String, arbiter: Option<Pubkey>) -> Result<()> {
+        instructions::activate_contract::activate_contract(ctx, contract_id, arbiter)
+    }
+
+    /*
+        Seller will submit the hash of the delivered work.
+        This starts the buyer review window.
+    */
+    pub fn submit_delivery(ctx: Context<SubmitDeliveryContext>, contract_id: String, delivery_hash: [u8; 32]) -> Result<()> {
+        instructions::submit_delivery::submit_delivery(ctx, contract_id, delivery_hash)
+    }
+
+    /*
+        Anyone can release funds to seller once the buyer review period after delivery is over
+        without buyer approving or disputing. Treasury will get buyer's dispute fee.
+    */
+    pub fn auto_release<'info>(ctx: Context<'_, '_, '_, 'info, AutoReleaseContext<'info>>, contract_id: String) -> Result<()> {
+        instructions::auto_release::auto_release(ctx, contract_id)
+    }
+
+    /*
+        Anyone can release the next period's payment of a recurring contract to seller once it is due.
+    */
+    pub fn release_period<'info>(ctx: Context<'_, '_, '_, 'info, ReleasePeriodContext<'info>>, contract_id: String) -> Result<()> {
+        instructions::release_period::release_period(ctx, contract_id)
+    }
+
+    /*
+        Buyer will object to the next period of a recurring contract.
+        Contract goes to dispute and admin decides on the period that was due,
+        the later periods are refunded to buyer when the decision is executed.
+    */
+    pub fn object_period(ctx: Context<ObjectPeriodContext>, contract_id: String) -> Result<()> {
+        instructions::object_period::object_period(ctx, contract_id)
+    }
+
+    /*
+        Seller will withdraw the vested part of a streamed contract.
     */
-    pub fn activate_contract(ctx: Context<ActivateContractContext>, contract_id: String,) -> Result<()> {
-        instructions::activate_contract::activate_contract(ctx, contract_id)
+    pub fn withdraw_vested<'info>(ctx: Context<'_, '_, '_, 'info, WithdrawVestedContext<'info>>, contract_id: String) -> Result<()> {
+        instructions::withdraw_vested::withdraw_vested(ctx, contract_id)
+    }
+
+    /*
+        Buyer will stop a streamed contract. 
+        Vested part goes to seller and unvested remainder is refunded to buyer.
+    */
+    pub fn stop_stream<'info>(ctx: Context<'_, '_, '_, 'info, StopStreamContext<'info>>, contract_id: String) -> Result<()> {
+        instructions::stop_stream::stop_stream(ctx, contract_id)
     }
 
     /*
         Buyer will release funds after satisfied with products seller will deliver.
-        Here, split will be true if buyer is dissatisfied
+        Here, seller_share_bps will be less than 10000 if buyer is dissatisfied 
+        and proposes to release only part of the amount to seller.
     */
-    pub fn buyer_approve(ctx: Context<BuyerApproveContext>, contract_id: String, split: bool) -> Result<()> {
-        instructions::buyer_approve::buyer_approve(ctx, contract_id, split)
+    pub fn buyer_approve(ctx: Context<BuyerApproveContext>, contract_id: String, seller_share_bps: u16) -> Result<()> {
+        instructions::buyer_approve::buyer_approve(ctx, contract_id, seller_share_bps)
     }
 
     /*
         Admin will approve if there is a dispute.
         decision value: 0 for both ok by default, 1 for seller, 2 for buyer, 3 for split
+        Decision is only recorded and executed by execute_resolution after RESOLUTION_DELAY,
+        or after the appeal window for disputes if it is longer.
     */
     pub fn admin_approve(ctx: Context<AdminApproveContext>, contract_id: String, decision: u8) -> Result<()> {
         instructions::admin_approve::admin_approve(ctx, contract_id, decision)
@@ -52,9 +167,244 @@ pub mod gig_basic_contract {
 
     /*
         Seller will approve the amount of funds to receive 
-        Here, seller_satisfied will be true if seller agree with split payment. Otherwise false
+        Here, seller_satisfied will be true if seller agree with the proposed seller_share_bps. Otherwise false
+        For team contracts, the lead seller approves and other members' token accounts are passed as remaining accounts.
     */
-    pub fn seller_approve(ctx: Context<SellerApproveContext>, contract_id: String, seller_satisfied: bool) -> Result<()> {
+    pub fn seller_approve<'info>(ctx: Context<'_, '_, '_, 'info, SellerApproveContext<'info>>, contract_id: String, seller_satisfied: bool) -> Result<()> {
         instructions::seller_approve::seller_approve(ctx, contract_id, seller_satisfied)
     }
+
+    /*
+        Admin will initialize the program config once after deployment.
+        Platform fees are collected in the treasury owned by the config PDA.
+    */
+    pub fn initialize_config(ctx: Context<InitializeConfigContext>, treasury_authority: Pubkey, admin_timelock: u32, guardian: Pubkey, verifier: Pubkey) -> Result<()> {
+        instructions::initialize_config::initialize_config(ctx, treasury_authority, admin_timelock, guardian, verifier)
+    }
+
+    /*
+        Treasury authority will withdraw collected platform fees from the treasury.
+    */
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasuryContext>, amount: u64) -> Result<()> {
+        instructions::withdraw_treasury::withdraw_treasury(ctx, amount)
+    }
+
+    /*
+        Admin will propose a new admin key. 
+        Proposing Pubkey::default() cancels a pending transfer.
+    */
+    pub fn propose_admin(ctx: Context<ProposeAdminContext>, new_admin: Pubkey) -> Result<()> {
+        instructions::propose_admin::propose_admin(ctx, new_admin)
+    }
+
+    /*
+        Proposed admin will accept the admin role after the timelock has passed.
+    */
+    pub fn accept_admin(ctx: Context<AcceptAdminContext>) -> Result<()> {
+        instructions::accept_admin::accept_admin(ctx)
+    }
+
+    /*
+        Guardian will pause or unpause the program in an emergency.
+        paused is a bitmask: 1 for new contracts, 2 for settlements
+    */
+    pub fn set_paused(ctx: Context<SetPausedContext>, paused: u8) -> Result<()> {
+        instructions::set_paused::set_paused(ctx, paused)
+    }
+
+    /*
+        Buyer will cancel the contract and get refunded if seller didn't activate it yet.
+        This is allowed even while the program is paused.
+    */
+    pub fn cancel_contract<'info>(ctx: Context<'_, '_, '_, 'info, CancelContractContext<'info>>, contract_id: String) -> Result<()> {
+        instructions::cancel_contract::cancel_contract(ctx, contract_id)
+    }
+
+    /*
+        Buyer will add funds to an active contract when the scope grows.
+        Seller has to co-sign the transaction to accept the new amount.
+    */
+    pub fn increase_amount(ctx: Context<IncreaseAmountContext>, contract_id: String, additional_amount: u64) -> Result<()> {
+        instructions::increase_amount::increase_amount(ctx, contract_id, additional_amount)
+    }
+
+    /*
+        Buyer or seller will propose new contract terms.
+        Amount can only be kept or reduced, the difference is refunded to buyer on acceptance.
+    */
+    pub fn propose_amendment(ctx: Context<ProposeAmendmentContext>, contract_id: String, deadline: u32, amount: u64) -> Result<()> {
+        instructions::propose_amendment::propose_amendment(ctx, contract_id, deadline, amount)
+    }
+
+    /*
+        The other party will accept the proposed amendment and it is applied to the contract.
+    */
+    pub fn accept_amendment(ctx: Context<AcceptAmendmentContext>, contract_id: String) -> Result<()> {
+        instructions::accept_amendment::accept_amendment(ctx, contract_id)
+    }
+
+    /*
+        Either party will withdraw or reject the proposed amendment.
+    */
+    pub fn cancel_amendment(ctx: Context<CancelAmendmentContext>, contract_id: String) -> Result<()> {
+        instructions::cancel_amendment::cancel_amendment(ctx, contract_id)
+    }
+
+    /*
+        Buyer or seller will counter the split proposal of the other party.
+        After MAX_SPLIT_ROUNDS counter-offers or when the proposal expired, contract goes to dispute.
+    */
+    pub fn counter_split(ctx: Context<CounterSplitContext>, contract_id: String, seller_share_bps: u16) -> Result<()> {
+        instructions::counter_split::counter_split(ctx, contract_id, seller_share_bps)
+    }
+
+    /*
+        Buyer or seller will accept the split proposal of the other party and funds are released.
+    */
+    pub fn accept_split<'info>(ctx: Context<'_, '_, '_, 'info, AcceptSplitContext<'info>>, contract_id: String) -> Result<()> {
+        instructions::accept_split::accept_split(ctx, contract_id)
+    }
+
+    /*
+        Buyer or seller will rate the other party from 1 to 5 once the contract is completed.
+        Each party can review a contract only once and the rating is added to the counterparty's profile.
+    */
+    pub fn submit_review(ctx: Context<SubmitReviewContext>, contract_id: String, rating: u8, content_hash: [u8; 32]) -> Result<()> {
+        instructions::submit_review::submit_review(ctx, contract_id, rating, content_hash)
+    }
+
+    /*
+        Admin will rotate the verifier that issues seller attestations.
+    */
+    pub fn set_verifier(ctx: Context<SetVerifierContext>, verifier: Pubkey) -> Result<()> {
+        instructions::set_verifier::set_verifier(ctx, verifier)
+    }
+
+    /*
+        Admin will set the largest share of the platform fee a buyer can give to a contract arbiter.
+    */
+    pub fn set_max_arbiter_fee(ctx: Context<SetMaxArbiterFeeContext>, max_arbiter_fee_bps: u16) -> Result<()> {
+        instructions::set_max_arbiter_fee::set_max_arbiter_fee(ctx, max_arbiter_fee_bps)
+    }
+
+    /*
+        Guardian will hand over its role of vetoing admin decisions to a new guardian.
+        Admin can't rotate it, otherwise it could replace the guardian before a decision gets vetoed.
+    */
+    pub fn set_guardian(ctx: Context<SetGuardianContext>, guardian: Pubkey) -> Result<()> {
+        instructions::set_guardian::set_guardian(ctx, guardian)
+    }
+
+    /*
+        Verifier will issue or renew an attestation for a wallet valid until expires_at.
+        Contracts created with require_verified can only be activated by sellers holding a valid one.
+    */
+    pub fn issue_verification(ctx: Context<IssueVerificationContext>, wallet: Pubkey, expires_at: u32) -> Result<()> {
+        instructions::issue_verification::issue_verification(ctx, wallet, expires_at)
+    }
+
+    /*
+        Verifier will revoke the attestation of a wallet.
+    */
+    pub fn revoke_verification(ctx: Context<RevokeVerificationContext>) -> Result<()> {
+        instructions::revoke_verification::revoke_verification(ctx)
+    }
+
+    /*
+        Admin will ban a wallet from starting, posting, applying to or activating contracts.
+        Contracts it is already part of can still be settled.
+    */
+    pub fn ban_wallet(ctx: Context<BanWalletContext>, wallet: Pubkey) -> Result<()> {
+        instructions::ban_wallet::ban_wallet(ctx, wallet)
+    }
+
+    /*
+        Admin will lift the ban of a wallet.
+    */
+    pub fn unban_wallet(ctx: Context<UnbanWalletContext>) -> Result<()> {
+        instructions::unban_wallet::unban_wallet(ctx)
+    }
+
+    /*
+        Admin will set the appeal window, appeal bond and the higher-tier arbiters resolving appeals.
+        appeal_window of 0 disables appeals, at least one arbiter is kept for appeals still open.
+    */
+    pub fn set_appeal_config(ctx: Context<SetAppealConfigContext>, appeal_window: u32, appeal_bond: u64, appeal_arbiters: Vec<Pubkey>) -> Result<()> {
+        instructions::set_appeal_config::set_appeal_config(ctx, appeal_window, appeal_bond, appeal_arbiters)
+    }
+
+    /*
+        Losing party will appeal a dispute decision within the appeal window by posting the appeal bond.
+        Either party can appeal a split decision. Settlement is frozen until the appeal is resolved.
+    */
+    pub fn appeal_decision(ctx: Context<AppealDecisionContext>, contract_id: String) -> Result<()> {
+        instructions::appeal_decision::appeal_decision(ctx, contract_id)
+    }
+
+    /*
+        Appeal arbiter will resolve an appeal. If the appeal fails the bond is forfeited to treasury,
+        otherwise the decision is reversed in favor of the appellant and the bond is returned.
+    */
+    pub fn resolve_appeal(ctx: Context<ResolveAppealContext>, contract_id: String, reverse: bool) -> Result<()> {
+        instructions::resolve_appeal::resolve_appeal(ctx, contract_id, reverse)
+    }
+
+    /*
+        Anyone will expire an appeal that was not resolved within APPEAL_RESOLUTION_WINDOW.
+        Original decision stands and can be executed after the veto delay, the bond is returned to the appellant.
+    */
+    pub fn expire_appeal(ctx: Context<ExpireAppealContext>, contract_id: String) -> Result<()> {
+        instructions::expire_appeal::expire_appeal(ctx, contract_id)
+    }
+
+    /*
+        Anyone will execute a recorded admin decision once the veto delay passed and it can't be appealed anymore.
+        For team contracts, token accounts of the other team members are passed as remaining accounts.
+        The resolving arbiter gets part of the forfeited collateral in arbiter_ata.
+    */
+    pub fn execute_resolution<'info>(ctx: Context<'_, '_, '_, 'info, ExecuteResolutionContext<'info>>, contract_id: String) -> Result<()> {
+        instructions::execute_resolution::execute_resolution(ctx, contract_id)
+    }
+
+    /*
+        Guardian will veto a recorded admin decision before it is executed.
+        Contract goes back to dispute or pending and has to be decided again.
+    */
+    pub fn veto_resolution(ctx: Context<VetoResolutionContext>, contract_id: String) -> Result<()> {
+        instructions::veto_resolution::veto_resolution(ctx, contract_id)
+    }
+
+    /*
+        Admin will approve many pending contracts with seller in one transaction.
+        Contract accounts are passed as writable remaining accounts, up to MAX_BATCH_SIZE.
+        Decisions are executed by execute_resolution after RESOLUTION_DELAY like admin_approve.
+    */
+    pub fn admin_approve_many(ctx: Context<AdminApproveManyContext>) -> Result<()> {
+        instructions::admin_approve_many::admin_approve_many(ctx)
+    }
+
+    /*
+        Anyone will execute many recorded admin decisions in one transaction, up to MAX_BATCH_SIZE.
+        Every contract is passed as a group of writable remaining accounts: contract, contract_ata, 
+        seller_ata, buyer_ata, arbiter_ata, buyer_profile and seller_profile. Team contracts use execute_resolution.
+        arbiter_ata is only needed when the decision pays an arbiter fee, any account can fill its slot otherwise.
+    */
+    pub fn execute_resolution_many<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteResolutionManyContext<'info>>) -> Result<()> {
+        instructions::execute_resolution_many::execute_resolution_many(ctx)
+    }
+
+    /*
+        Treasury authority will propose a new key allowed to withdraw platform fees.
+        Proposing Pubkey::default() cancels a pending transfer.
+    */
+    pub fn propose_treasury_authority(ctx: Context<ProposeTreasuryAuthorityContext>, new_treasury_authority: Pubkey) -> Result<()> {
+        instructions::propose_treasury_authority::propose_treasury_authority(ctx, new_treasury_authority)
+    }
+
+    /*
+        Proposed treasury authority will accept the role after the admin timelock has passed.
+    */
+    pub fn accept_treasury_authority(ctx: Context<AcceptTreasuryAuthorityContext>) -> Result<()> {
+        instructions::accept_treasury_authority::accept_treasury_authority(ctx)
+    }
 }
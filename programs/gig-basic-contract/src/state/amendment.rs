@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+
+#[account]
+pub struct Amendment {
+    pub contract: Pubkey,
+    pub proposer: Pubkey, // buyer or seller, the other party has to accept
+    pub deadline: u32,
+    pub amount: u64, // can only stay the same or decrease, increases go through increase_amount
+    pub base_deadline: u32, // contract terms the amendment was proposed against
+    pub base_amount: u64,
+    pub bump: u8,
+}
+
+impl Amendment {
+    pub const LEN: usize = size_of::<Self>();
+}
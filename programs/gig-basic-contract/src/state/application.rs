@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+
+#[account]
+pub struct Application {
+    pub contract: Pubkey, // open job the seller applied to
+    pub seller: Pubkey,
+    pub bid: u64,
+    pub bump: u8,
+}
+
+impl Application {
+    pub const LEN: usize = size_of::<Self>();
+}
@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+
+#[account]
+pub struct Banned {
+    pub wallet: Pubkey, // can't open or activate new contracts, existing ones still settle
+    pub banned_at: u32,
+    pub bump: u8,
+}
+
+impl Banned {
+    pub const LEN: usize = size_of::<Self>();
+}
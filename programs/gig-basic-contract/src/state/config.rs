@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+
+use crate::constants::MAX_APPEAL_ARBITERS;
+
+#[account]
+pub struct Config {
+    pub admin: Pubkey, // resolves disputes, rotated through propose_admin/accept_admin
+    pub pending_admin: Pubkey,
+    pub admin_timelock: u32, // seconds a proposed admin has to wait before accepting
+    pub admin_transfer_ready_at: u32,
+    pub treasury_authority: Pubkey, // only key allowed to withdraw platform fees, rotated like the admin
+    pub pending_treasury_authority: Pubkey,
+    pub treasury_transfer_ready_at: u32,
+    pub guardian: Pubkey, // only key allowed to pause the program, hands its role over through set_guardian
+    pub verifier: Pubkey, // only key allowed to issue Verified attestations
+    pub appeal_window: u32, // seconds the losing party has to appeal a dispute decision, 0 disables appeals
+    pub appeal_bond: u64, // posted by the appellant
+    pub appeal_arbiters: [Pubkey; MAX_APPEAL_ARBITERS], // higher-tier arbiters resolving appeals
+    pub max_arbiter_fee_bps: u16, // cap on the share of the platform fee a buyer can give to the contract arbiter
+    pub paused: u8, // bitmask of PAUSE_NEW_CONTRACTS and PAUSE_SETTLEMENTS
+    pub bump: u8,
+}
+
+impl Config {
+    pub const LEN: usize = size_of::<Self>();
+
+    pub fn is_paused(&self, flag: u8) -> bool {
+        self.paused & flag != 0
+    }
+
+    pub fn is_appeal_arbiter(&self, key: Pubkey) -> bool {
+        key != Pubkey::default() && self.appeal_arbiters.contains(&key)
+    }
+}
@@ -15,8 +15,38 @@ pub struct Contract {
     pub deadline: u32,
     pub amount: u64,
     pub dispute: u64,
-    pub split: bool,
+    pub seller_share_bps: u16, // currently proposed share, 10000 means no split
+    pub buyer_proposal_bps: u16, // share buyer originally proposed, used by an admin split decision
+    pub split_proposer: Pubkey, // who made the current split proposal, default if none
+    pub split_round: u8, // number of counter-offers made so far
+    pub split_expires_at: u32, // counterparty has to answer the proposal before this time
     pub seller_satisfied: bool, // regarding split decision
+    pub delivery_hash: [u8; 32], // hash of the delivered work submitted by seller
+    pub delivered_at: u32,
+    pub review_period: u32, // seconds buyer has to review a delivery before it can be auto released
+    pub kind: ContractKind,
+    pub period_count: u16, // number of periods escrowed up front for recurring contracts
+    pub period_interval: u32, // seconds between period releases
+    pub periods_released: u16,
+    pub next_release_at: u32,
+    pub released: u64, // part of amount already paid out before completion
+    pub team: [TeamMember; MAX_TEAM_SIZE], // sellers sharing the seller payout, first one is the lead seller
+    pub team_size: u8, // 0 for single seller contracts
+    pub bid_deadline: u32, // open job can be awarded to the lowest bid by anyone after this time
+    pub lowest_bid: u64, // amount is the ceiling price while the job is open
+    pub lowest_bidder: Pubkey,
+    pub dispute_raiser: Pubkey, // party that sent the contract to dispute, default if it escalated on timeout
+    pub require_verified: bool, // sellers need a valid Verified attestation to activate
+    pub arbiter: Pubkey, // resolves disputes instead of the platform admin, default if none
+    pub arbiter_fee_bps: u16, // share of the platform fee paid to the arbiter when it resolves the contract
+    pub decision: u8, // recorded admin decision waiting for execution, same values as admin_approve
+    pub disputed: bool, // decision resolves a dispute rather than an unanswered approval
+    pub resolved_by: Pubkey, // arbiter whose decision is executed and who gets the arbiter fee
+    pub appeal_deadline: u32, // losing party can appeal the decision until this time
+    pub appellant: Pubkey, // default if the decision was not appealed
+    pub appeal_bond: u64, // posted by the appellant, forfeited to treasury if the appeal fails
+    pub appeal_resolve_by: u32, // original decision stands if the appeal is not resolved until this time
+    pub resolution_ready_at: u32, // decision can be executed from this time unless the guardian vetoes it
     pub buyer_approved: bool,
     pub seller_approved: bool,
     pub admin_approved: bool,
@@ -25,6 +55,204 @@ pub struct Contract {
 
 impl Contract {
     pub const LEN: usize = size_of::<Self>();
+
+    pub fn is_team(&self) -> bool {
+        self.team_size > 0
+    }
+
+    pub fn team_members(&self) -> &[TeamMember] {
+        &self.team[..self.team_size as usize]
+    }
+
+    // Every team member posts the same collateral as a single seller would
+    pub fn seller_count(&self) -> u64 {
+        if self.is_team() { self.team_size as u64 } else { 1 }
+    }
+
+    // Amount still held in escrow, excluding collateral
+    pub fn remaining(&self) -> u64 {
+        self.amount - self.released
+    }
+
+    // Part of the remaining amount a settlement decides on. A dispute on a recurring contract
+    // only covers the period that was due, later periods are not worked and go back to buyer.
+    pub fn settled_amount(&self) -> u64 {
+        if self.kind == ContractKind::Recurring && self.disputed {
+            self.period_payment()
+        } else {
+            self.remaining()
+        }
+    }
+
+    // Platform fee is taken from the settled amount first, then the rest is split
+    // between seller and buyer by seller_share_bps. seller_collateral is returned to 
+    // each seller of a team. Collateral not returned to a party is forfeited to the treasury.
+    pub fn payout(&self, seller_share_bps: u16, seller_collateral: u64, buyer_collateral: u64) -> Payout {
+        let settled = self.settled_amount();
+        let fee = settled * PLATFORM_FEE_PERCENT / 100;
+        let net = settled - fee;
+        let seller_share = net * seller_share_bps as u64 / BPS_DENOMINATOR as u64;
+        let posted = self.dispute * (self.seller_count() + 1);
+        let forfeited = posted - seller_collateral * self.seller_count() - buyer_collateral;
+
+        Payout {
+            seller: seller_share,
+            seller_collateral,
+            buyer: net - seller_share + buyer_collateral + self.remaining() - settled,
+            treasury: fee + forfeited,
+            platform_fee: fee,
+            arbiter: 0,
+        }
+    }
+
+    // Amount of the contract paid to the seller side once it completes with payout,
+    // counted as completed volume in both profiles. Has to be read before released is updated.
+    pub fn paid_volume(&self, payout: &Payout) -> u64 {
+        self.released + payout.seller
+    }
+
+    // Key allowed to resolve this contract in admin_approve
+    pub fn resolver(&self, admin: Pubkey) -> Pubkey {
+        if self.arbiter != Pubkey::default() { self.arbiter } else { admin }
+    }
+
+    // Same as payout, but whoever resolves the contract gets ARBITER_COLLATERAL_PERCENT of the
+    // forfeited collateral, and a contract arbiter also gets its share of the platform fee
+    pub fn arbitrated_payout(&self, seller_share_bps: u16, seller_collateral: u64, buyer_collateral: u64) -> Payout {
+        let mut payout = self.payout(seller_share_bps, seller_collateral, buyer_collateral);
+
+        let forfeited = payout.treasury - payout.platform_fee;
+        let mut arbiter_fee = forfeited * ARBITER_COLLATERAL_PERCENT / 100;
+
+        if self.arbiter != Pubkey::default() {
+            let fee_share = payout.platform_fee * self.arbiter_fee_bps as u64 / BPS_DENOMINATOR as u64;
+            payout.platform_fee -= fee_share;
+            arbiter_fee += fee_share;
+        }
+
+        payout.arbiter = arbiter_fee;
+        payout.treasury -= arbiter_fee;
+        payout
+    }
+
+    // Payout of a dispute decision: 0 for both ok by default, 1 for seller, 2 for buyer, 3 for split
+    pub fn dispute_payout(&self, decision: u8) -> Payout {
+        match decision {
+            // admin agrees with seller
+            // transfer payment to seller and treasury gets buyer's dispute fee
+            1 => self.arbitrated_payout(BPS_DENOMINATOR, self.dispute, 0),
+            // admin agrees with buyer
+            // transfer payment to buyer and treasury gets seller's dispute fee
+            2 => self.arbitrated_payout(0, 0, self.dispute),
+            // admin agrees with split decision
+            // split payment by the share buyer originally proposed, not the latest counter-offer,
+            // and treasury gets half of dispute fee from both parties
+            _ => self.arbitrated_payout(self.buyer_proposal_bps, self.dispute / 2, self.dispute / 2),
+        }
+    }
+
+    // Records an admin decision that execute_resolution executes once the veto delay
+    // and, for disputes, the appeal window passed
+    pub fn record_resolution(&mut self, decision: u8, resolved_by: Pubkey, appeal_window: u32, current_timestamp: u32) {
+        self.disputed = self.status == ContractStatus::Dispute;
+        self.status = ContractStatus::Resolved;
+        self.decision = decision;
+        self.resolved_by = resolved_by;
+        self.appeal_deadline = current_timestamp + appeal_window;
+        self.resolution_ready_at = current_timestamp + appeal_window.max(RESOLUTION_DELAY);
+        self.admin_approved = true;
+    }
+
+    // Party a dispute decision went against, a split decision has no loser
+    pub fn dispute_loser(&self, decision: u8) -> Pubkey {
+        match decision {
+            1 => self.buyer,
+            2 => self.seller,
+            _ => Pubkey::default(),
+        }
+    }
+
+    pub fn is_split_negotiating(&self) -> bool {
+        self.split_proposer != Pubkey::default()
+    }
+
+    pub fn is_split_expired(&self, current_timestamp: u32) -> bool {
+        self.is_split_negotiating() && current_timestamp > self.split_expires_at
+    }
+
+    pub fn is_review_over(&self, current_timestamp: u32) -> bool {
+        self.status == ContractStatus::Delivered && current_timestamp >= self.delivered_at.saturating_add(self.review_period)
+    }
+
+    pub fn is_last_period(&self) -> bool {
+        self.periods_released + 1 == self.period_count
+    }
+
+    // Last period also pays out the rounding remainder
+    pub fn period_payment(&self) -> u64 {
+        if self.is_last_period() {
+            self.remaining()
+        } else {
+            self.amount / self.period_count as u64
+        }
+    }
+
+    // Part of a streamed amount vested linearly from start_time to deadline
+    pub fn vested(&self, current_timestamp: u32) -> u64 {
+        if current_timestamp >= self.deadline {
+            return self.amount;
+        }
+        if current_timestamp <= self.start_time {
+            return 0;
+        }
+
+        let elapsed = (current_timestamp - self.start_time) as u128;
+        let duration = (self.deadline - self.start_time) as u128;
+        (self.amount as u128 * elapsed / duration) as u64
+    }
+
+    // Releases payment out of the remaining amount to seller minus platform fee.
+    // On completion whatever is left goes back to buyer and collateral is returned to both parties.
+    pub fn release_payout(&self, payment: u64, complete: bool) -> Payout {
+        let fee = payment * PLATFORM_FEE_PERCENT / 100;
+        let (refund, collateral) = if complete {
+            (self.remaining() - payment, self.dispute)
+        } else {
+            (0, 0)
+        };
+
+        Payout {
+            seller: payment - fee,
+            seller_collateral: collateral,
+            buyer: refund + collateral,
+            treasury: fee,
+            platform_fee: fee,
+            arbiter: 0,
+        }
+    }
+
+    // Records a new split proposal that the other party has to answer in time
+    pub fn propose_split(&mut self, proposer: Pubkey, seller_share_bps: u16, current_timestamp: u32) {
+        self.seller_share_bps = seller_share_bps;
+        self.split_proposer = proposer;
+        self.split_expires_at = current_timestamp + SPLIT_RESPONSE_WINDOW;
+    }
+}
+
+pub struct Payout {
+    pub seller: u64, // shared by team members by their share_bps
+    pub seller_collateral: u64, // returned to each seller
+    pub buyer: u64,
+    pub treasury: u64, // platform fee plus forfeited collateral
+    pub platform_fee: u64,
+    pub arbiter: u64, // carved out of the treasury share
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct TeamMember {
+    pub seller: Pubkey,
+    pub share_bps: u16, // share of the seller payout, all members add up to 10000
+    pub activated: bool, // posted collateral
 }
 
 impl Default for Contract {
@@ -38,8 +266,38 @@ impl Default for Contract {
             deadline: 0,
             amount: 0,
             dispute: 0,
-            split: false,
+            seller_share_bps: BPS_DENOMINATOR,
+            buyer_proposal_bps: BPS_DENOMINATOR,
+            split_proposer: Pubkey::default(),
+            split_round: 0,
+            split_expires_at: 0,
             seller_satisfied: false,
+            delivery_hash: [0; 32],
+            delivered_at: 0,
+            review_period: 0,
+            kind: ContractKind::OneTime,
+            period_count: 0,
+            period_interval: 0,
+            periods_released: 0,
+            next_release_at: 0,
+            released: 0,
+            team: [TeamMember::default(); MAX_TEAM_SIZE],
+            team_size: 0,
+            bid_deadline: 0,
+            lowest_bid: 0,
+            lowest_bidder: Pubkey::default(),
+            dispute_raiser: Pubkey::default(),
+            require_verified: false,
+            arbiter: Pubkey::default(),
+            arbiter_fee_bps: 0,
+            decision: 0,
+            disputed: false,
+            resolved_by: Pubkey::default(),
+            appeal_deadline: 0,
+            appellant: Pubkey::default(),
+            appeal_bond: 0,
+            appeal_resolve_by: 0,
+            resolution_ready_at: 0,
             buyer_approved: false,
             seller_approved: false,
             admin_approved: false,
@@ -48,6 +306,13 @@ impl Default for Contract {
     }
 }
 
+#[derive(Eq, AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub enum ContractKind {
+    OneTime,
+    Recurring,
+    Stream,
+}
+
 #[derive(Eq, AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
 pub enum ContractStatus {
     NoExist,
@@ -56,4 +321,167 @@ pub enum ContractStatus {
     Pending,
     Dispute,
     Completed,
+    Cancelled,
+    Delivered,
+    Open,
+    Resolved,
+    Appealed,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contract(amount: u64, dispute: u64, team_size: u8) -> Contract {
+        Contract {
+            buyer: Pubkey::new_unique(),
+            seller: Pubkey::new_unique(),
+            amount,
+            dispute,
+            team_size,
+            buyer_proposal_bps: BPS_DENOMINATOR,
+            ..Contract::default()
+        }
+    }
+
+    // Everything paid out of a settlement, collateral is returned to each seller of a team
+    fn total(contract: &Contract, payout: &Payout) -> u64 {
+        payout.seller + payout.seller_collateral * contract.seller_count() + payout.buyer + payout.treasury + payout.arbiter
+    }
+
+    fn escrow(contract: &Contract) -> u64 {
+        contract.remaining() + contract.dispute * (contract.seller_count() + 1)
+    }
+
+    #[test]
+    fn dispute_payout_adds_up_for_every_decision_and_team_size() {
+        for team_size in 0..=MAX_TEAM_SIZE as u8 {
+            let mut contract = contract(1_000_003, 50_001, team_size);
+            contract.buyer_proposal_bps = 3_333;
+
+            for decision in 1..=3 {
+                let payout = contract.dispute_payout(decision);
+                assert_eq!(total(&contract, &payout), escrow(&contract), "team {} decision {}", team_size, decision);
+            }
+        }
+    }
+
+    #[test]
+    fn dispute_payout_follows_decision() {
+        let mut contract = contract(1_000, 100, 0);
+        contract.buyer_proposal_bps = 2_500;
+
+        let seller = contract.dispute_payout(1);
+        assert_eq!((seller.seller, seller.seller_collateral, seller.buyer), (900, 100, 0));
+
+        let buyer = contract.dispute_payout(2);
+        assert_eq!((buyer.seller, buyer.seller_collateral, buyer.buyer), (0, 0, 1_000));
+
+        // Split uses the buyer's original proposal, not the latest counter-offer
+        contract.seller_share_bps = 9_000;
+        let split = contract.dispute_payout(3);
+        assert_eq!((split.seller, split.seller_collateral, split.buyer), (225, 50, 725));
+    }
+
+    #[test]
+    fn recurring_dispute_only_settles_due_period() {
+        let mut contract = contract(1_000, 100, 0);
+        contract.kind = ContractKind::Recurring;
+        contract.period_count = 4;
+        contract.periods_released = 1;
+        contract.released = 250;
+        contract.disputed = true;
+
+        let payout = contract.dispute_payout(1);
+        assert_eq!(payout.seller, 225);
+        assert_eq!(payout.buyer, 500);
+        assert_eq!(total(&contract, &payout), escrow(&contract));
+    }
+
+    #[test]
+    fn last_period_pays_rounding_remainder() {
+        let mut contract = contract(1_000, 100, 0);
+        contract.kind = ContractKind::Recurring;
+        contract.period_count = 3;
+
+        let mut paid = 0;
+        for _ in 0..contract.period_count {
+            let payment = contract.period_payment();
+            let payout = contract.release_payout(payment, contract.is_last_period());
+            paid += payout.seller + payout.treasury;
+            contract.released += payment;
+            contract.periods_released += 1;
+        }
+        assert_eq!(paid, 1_000);
+        assert_eq!(contract.remaining(), 0);
+    }
+
+    #[test]
+    fn release_payout_adds_up_on_completion() {
+        let mut contract = contract(1_000_001, 7_777, 0);
+        contract.released = 333_333;
+
+        let payout = contract.release_payout(123_457, true);
+        assert_eq!(total(&contract, &payout), escrow(&contract));
+
+        let payout = contract.release_payout(123_457, false);
+        assert_eq!(total(&contract, &payout), 123_457);
+    }
+
+    #[test]
+    fn vested_is_linear_between_start_and_deadline() {
+        let mut contract = contract(1_000, 0, 0);
+        contract.start_time = 100;
+        contract.deadline = 400;
+
+        assert_eq!(contract.vested(50), 0);
+        assert_eq!(contract.vested(100), 0);
+        assert_eq!(contract.vested(200), 333);
+        assert_eq!(contract.vested(399), 996);
+        assert_eq!(contract.vested(400), 1_000);
+        assert_eq!(contract.vested(u32::MAX), 1_000);
+    }
+
+    #[test]
+    fn last_vesting_releases_everything() {
+        let mut contract = contract(1_000, 100, 0);
+        contract.start_time = 0;
+        contract.deadline = 3;
+
+        let first = contract.vested(1);
+        let payout = contract.release_payout(first, false);
+        contract.released += first;
+        let mut paid = payout.seller + payout.treasury;
+
+        let last = contract.vested(3) - contract.released;
+        let payout = contract.release_payout(last, true);
+        paid += payout.seller + payout.treasury + payout.buyer + payout.seller_collateral;
+
+        assert_eq!(paid, 1_000 + 2 * 100);
+    }
+
+    #[test]
+    fn arbitrated_payout_shares_fee_with_arbiter() {
+        for arbiter_fee_bps in [0, 1, 2_500, 9_999, BPS_DENOMINATOR] {
+            let mut contract = contract(999_999, 33_333, 0);
+            contract.arbiter = Pubkey::new_unique();
+            contract.arbiter_fee_bps = arbiter_fee_bps;
+
+            let payout = contract.arbitrated_payout(BPS_DENOMINATOR, contract.dispute, 0);
+            let fee_share = 99_999 * arbiter_fee_bps as u64 / BPS_DENOMINATOR as u64;
+            assert_eq!(payout.arbiter, 33_333 * ARBITER_COLLATERAL_PERCENT / 100 + fee_share);
+            assert_eq!(payout.platform_fee, 99_999 - fee_share);
+            assert_eq!(total(&contract, &payout), escrow(&contract));
+        }
+    }
+
+    #[test]
+    fn platform_admin_gets_no_fee_share() {
+        let mut contract = contract(1_000, 100, 0);
+        contract.arbiter_fee_bps = 5_000;
+
+        let payout = contract.arbitrated_payout(BPS_DENOMINATOR, contract.dispute, 0);
+        assert_eq!(payout.arbiter, 50);
+        assert_eq!(payout.platform_fee, 100);
+    }
 }
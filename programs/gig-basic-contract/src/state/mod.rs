@@ -1,3 +1,17 @@
 pub use contract::*;
+pub use config::*;
+pub use amendment::*;
+pub use application::*;
+pub use profile::*;
+pub use review::*;
+pub use verified::*;
+pub use banned::*;
 
-pub mod contract;
\ No newline at end of file
+pub mod contract;
+pub mod config;
+pub mod amendment;
+pub mod application;
+pub mod profile;
+pub mod review;
+pub mod verified;
+pub mod banned;
\ No newline at end of file
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+
+use crate::constants::*;
+
+#[account]
+pub struct UserProfile {
+    pub wallet: Pubkey,
+    pub contract_count: u32, // contracts indexed in pages so far, as buyer or seller
+    pub buyer_count: u32,
+    pub seller_count: u32,
+    pub completed_count: u32,
+    pub volume: u64, // total amount paid to the seller side of completed contracts
+    pub disputes_raised: u32,
+    pub disputes_lost: u32, // disputes admin decided fully for the counterparty
+    pub rating_sum: u64, // average rating is rating_sum / rating_count
+    pub rating_count: u32,
+    pub bump: u8,
+}
+
+impl UserProfile {
+    pub const LEN: usize = size_of::<Self>();
+
+    // Page the next contract will be written to
+    pub fn page_index(&self) -> u32 {
+        self.contract_count / CONTRACT_PAGE_SIZE as u32
+    }
+
+    pub fn add_contract(&mut self, page: &mut ContractPage, wallet: Pubkey, contract: Pubkey, as_buyer: bool, bump: u8, page_bump: u8) {
+        self.wallet = wallet;
+        self.bump = bump;
+
+        page.wallet = wallet;
+        page.index = self.page_index();
+        page.contracts[self.contract_count as usize % CONTRACT_PAGE_SIZE] = contract;
+        page.bump = page_bump;
+
+        self.contract_count += 1;
+        if as_buyer {
+            self.buyer_count += 1;
+        } else {
+            self.seller_count += 1;
+        }
+    }
+
+    // Only the buyer and the lead seller of a team contract have their completions recorded
+    pub fn record_completion(&mut self, amount: u64) {
+        self.completed_count += 1;
+        self.volume += amount;
+    }
+
+    pub fn add_rating(&mut self, rating: u8) {
+        self.rating_sum += rating as u64;
+        self.rating_count += 1;
+    }
+
+    pub fn record_dispute(&mut self, raised: bool, lost: bool) {
+        if raised {
+            self.disputes_raised += 1;
+        }
+        if lost {
+            self.disputes_lost += 1;
+        }
+    }
+}
+
+#[account]
+pub struct ContractPage {
+    pub wallet: Pubkey,
+    pub index: u32,
+    pub contracts: [Pubkey; CONTRACT_PAGE_SIZE], // filled in order, unused slots are default
+    pub bump: u8,
+}
+
+impl ContractPage {
+    pub const LEN: usize = size_of::<Self>();
+}
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+
+#[account]
+pub struct Review {
+    pub contract: Pubkey,
+    pub reviewer: Pubkey,
+    pub reviewee: Pubkey, // counterparty of the reviewer in the contract
+    pub rating: u8, // 1 to 5
+    pub content_hash: [u8; 32], // hash of the review text stored off-chain
+    pub bump: u8,
+}
+
+impl Review {
+    pub const LEN: usize = size_of::<Self>();
+}
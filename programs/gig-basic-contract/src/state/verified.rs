@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+
+#[account]
+pub struct Verified {
+    pub wallet: Pubkey,
+    pub expires_at: u32, // attestation is valid until this time
+    pub bump: u8,
+}
+
+impl Verified {
+    pub const LEN: usize = size_of::<Self>();
+
+    pub fn is_valid(&self, now: u32) -> bool {
+        now < self.expires_at
+    }
+}
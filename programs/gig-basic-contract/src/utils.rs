@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{ self, Token, TokenAccount, Transfer as SplTransfer };
+
+use crate::state::contract::*;
+use crate::constants::{
+    CONTRACT_SEED,
+    BPS_DENOMINATOR,
+    PAY_TOKEN_MINT_ADDRESS
+};
+use crate::errors::{
+    GigContractError
+};
+
+// Transfers paytoken out of the contract account, signed by the contract PDA
+pub fn transfer_from_contract<'info>(
+    token_program: &Program<'info, Token>,
+    contract: &Account<'info, Contract>,
+    contract_ata: &Account<'info, TokenAccount>,
+    destination: &impl ToAccountInfo<'info>,
+    bump: u8,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    token::transfer(
+    CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        SplTransfer {
+            from: contract_ata.to_account_info(),
+            to: destination.to_account_info(),
+            authority: contract.to_account_info(),
+        },
+        &[&[CONTRACT_SEED.as_bytes(), contract.buyer.as_ref(), contract.contract_id.as_bytes(), &[bump]]],
+    ),
+    amount,
+    )
+}
+
+// Pays the seller side of a payout. For team contracts the seller payout is shared by
+// share_bps and every activated member gets its collateral back. seller_ata belongs to 
+// the lead seller, team_atas are token accounts of the other members in team order.
+pub fn pay_sellers<'info>(
+    token_program: &Program<'info, Token>,
+    contract: &Account<'info, Contract>,
+    contract_ata: &Account<'info, TokenAccount>,
+    seller_ata: &Account<'info, TokenAccount>,
+    team_atas: &[AccountInfo<'info>],
+    bump: u8,
+    payout: &Payout,
+) -> Result<()> {
+    if !contract.is_team() {
+        return transfer_from_contract(token_program, contract, contract_ata, seller_ata, bump, payout.seller + payout.seller_collateral);
+    }
+
+    let members = contract.team_members();
+    require!(team_atas.len() >= members.len() - 1, GigContractError::InvalidTeamAccount);
+
+    let mut paid = 0;
+    for (index, member) in members.iter().enumerate() {
+        // Last member gets the rounding remainder
+        let share = if index == members.len() - 1 {
+            payout.seller - paid
+        } else {
+            payout.seller * member.share_bps as u64 / BPS_DENOMINATOR as u64
+        };
+        paid += share;
+
+        let collateral = if member.activated { payout.seller_collateral } else { 0 };
+
+        if index == 0 {
+            transfer_from_contract(token_program, contract, contract_ata, seller_ata, bump, share + collateral)?;
+        } else {
+            let member_ata = &team_atas[index - 1];
+            require_keys_eq!(*member_ata.owner, token::ID, GigContractError::InvalidTeamAccount);
+
+            let member_token_account = TokenAccount::try_deserialize(&mut &member_ata.try_borrow_data()?[..])?;
+            require_keys_eq!(member_token_account.owner, member.seller, GigContractError::InvalidTeamAccount);
+            require_keys_eq!(member_token_account.mint, PAY_TOKEN_MINT_ADDRESS, GigContractError::InvalidTeamAccount);
+
+            transfer_from_contract(token_program, contract, contract_ata, member_ata, bump, share + collateral)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,179 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::{ associated_token, token::spl_token };
+use common::*;
+use gig_basic_contract::{ accounts, instruction };
+use gig_basic_contract::constants::{ APPEAL_RESOLUTION_WINDOW, PAUSE_SETTLEMENTS, RESOLUTION_DELAY };
+use gig_basic_contract::errors::GigContractError;
+use gig_basic_contract::state::contract::ContractStatus;
+
+const APPEAL_WINDOW: u32 = 3 * 24 * 60 * 60;
+const APPEAL_BOND: u64 = 10;
+
+// Dispute raised by buyer and decided for the seller, appeal arbiter set in config
+fn decided_dispute(env: &mut Env) -> (Parties, Pubkey) {
+    let appeal_arbiter = env.wallet();
+    env.update_config(|config| {
+        config.appeal_window = APPEAL_WINDOW;
+        config.appeal_bond = APPEAL_BOND;
+        config.appeal_arbiters[0] = appeal_arbiter;
+    });
+
+    let parties = env.active_contract(1_000, 100);
+    env.update_contract(&parties, |contract| {
+        contract.status = ContractStatus::Dispute;
+        contract.dispute_raiser = parties.buyer;
+    });
+    env.token_account(parties.buyer, APPEAL_BOND);
+    env.token_account(appeal_arbiter, 0);
+    let admin = env.admin;
+    env.token_account(admin, 0);
+    env.admin_approve(&parties, admin, 1).unwrap();
+
+    (parties, appeal_arbiter)
+}
+
+fn appeal(env: &mut Env, parties: &Parties, appellant: Pubkey) -> std::result::Result<(), ProgramError> {
+    env.process(
+        accounts::AppealDecisionContext {
+            appellant,
+            contract: parties.contract,
+            config: config_key(),
+            appellant_ata: ata(&appellant),
+            contract_ata: ata(&parties.contract),
+            token_program: spl_token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::AppealDecision { contract_id: parties.contract_id.clone() },
+    )
+}
+
+fn resolve_appeal(env: &mut Env, parties: &Parties, appeal_arbiter: Pubkey, reverse: bool) -> std::result::Result<(), ProgramError> {
+    env.process(
+        accounts::ResolveAppealContext {
+            appeal_arbiter,
+            contract: parties.contract,
+            config: config_key(),
+            appellant_ata: ata(&parties.buyer),
+            treasury_ata: ata(&config_key()),
+            contract_ata: ata(&parties.contract),
+            token_program: spl_token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::ResolveAppeal { contract_id: parties.contract_id.clone(), reverse },
+    )
+}
+
+fn expire_appeal(env: &mut Env, parties: &Parties) -> std::result::Result<(), ProgramError> {
+    let signer = env.wallet();
+    env.process(
+        accounts::ExpireAppealContext {
+            signer,
+            contract: parties.contract,
+            config: config_key(),
+            appellant_ata: ata(&parties.buyer),
+            contract_ata: ata(&parties.contract),
+            token_program: spl_token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::ExpireAppeal { contract_id: parties.contract_id.clone() },
+    )
+}
+
+#[test]
+fn only_loser_can_appeal_in_time() {
+    let mut env = Env::new();
+    let (parties, _) = decided_dispute(&mut env);
+
+    assert_eq!(appeal(&mut env, &parties, parties.seller), Err(error(GigContractError::InvalidAppellant)));
+
+    advance_time(APPEAL_WINDOW);
+    assert_eq!(appeal(&mut env, &parties, parties.buyer), Err(error(GigContractError::CantAppeal)));
+
+    // Decision is executable once the appeal window passed
+    let admin = env.admin;
+    env.execute_resolution(&parties, Some(ata(&admin))).unwrap();
+    assert_eq!(env.balance(parties.seller), 900 + 100);
+}
+
+#[test]
+fn reversed_appeal_waits_for_veto_delay() {
+    let mut env = Env::new();
+    let (parties, appeal_arbiter) = decided_dispute(&mut env);
+
+    appeal(&mut env, &parties, parties.buyer).unwrap();
+    assert!(env.contract(&parties.buyer, &parties.contract_id).status == ContractStatus::Appealed);
+    assert_eq!(appeal(&mut env, &parties, parties.buyer), Err(error(GigContractError::CantAppeal)));
+
+    let wallet = env.wallet();
+    assert_eq!(resolve_appeal(&mut env, &parties, wallet, true), Err(error(GigContractError::InvalidAppealArbiter)));
+    resolve_appeal(&mut env, &parties, appeal_arbiter, true).unwrap();
+
+    let contract = env.contract(&parties.buyer, &parties.contract_id);
+    assert_eq!(contract.decision, 2);
+    assert_eq!(contract.resolved_by, appeal_arbiter);
+    assert_eq!(contract.resolution_ready_at, now() + RESOLUTION_DELAY);
+    assert_eq!(env.balance(parties.buyer), APPEAL_BOND);
+
+    assert_eq!(env.execute_resolution(&parties, Some(ata(&appeal_arbiter))), Err(error(GigContractError::ResolutionNotReady)));
+    advance_time(RESOLUTION_DELAY);
+    env.execute_resolution(&parties, Some(ata(&appeal_arbiter))).unwrap();
+
+    // Buyer is refunded less the platform fee, the appeal arbiter gets half of the seller's forfeited collateral
+    assert_eq!(env.balance(parties.buyer), APPEAL_BOND + 900 + 100);
+    assert_eq!(env.balance(appeal_arbiter), 50);
+}
+
+#[test]
+fn rejected_appeal_forfeits_bond() {
+    let mut env = Env::new();
+    let (parties, appeal_arbiter) = decided_dispute(&mut env);
+
+    appeal(&mut env, &parties, parties.buyer).unwrap();
+    resolve_appeal(&mut env, &parties, appeal_arbiter, false).unwrap();
+
+    let contract = env.contract(&parties.buyer, &parties.contract_id);
+    assert_eq!(contract.decision, 1);
+    assert_eq!(env.balance(parties.buyer), 0);
+    assert_eq!(env.balance(config_key()), APPEAL_BOND);
+}
+
+#[test]
+fn expired_appeal_waits_for_veto_delay() {
+    let mut env = Env::new();
+    let (parties, _) = decided_dispute(&mut env);
+
+    appeal(&mut env, &parties, parties.buyer).unwrap();
+    assert_eq!(expire_appeal(&mut env, &parties), Err(error(GigContractError::AppealNotExpired)));
+
+    advance_time(APPEAL_RESOLUTION_WINDOW);
+    expire_appeal(&mut env, &parties).unwrap();
+    assert_eq!(env.balance(parties.buyer), APPEAL_BOND);
+
+    // Guardian gets the same veto delay as for a resolved appeal
+    let admin = env.admin;
+    assert_eq!(env.execute_resolution(&parties, Some(ata(&admin))), Err(error(GigContractError::ResolutionNotReady)));
+    advance_time(RESOLUTION_DELAY);
+    env.execute_resolution(&parties, Some(ata(&admin))).unwrap();
+    assert_eq!(env.balance(parties.seller), 900 + 100);
+}
+
+#[test]
+fn paused_settlements_freeze_appeals() {
+    let mut env = Env::new();
+    let (parties, appeal_arbiter) = decided_dispute(&mut env);
+    appeal(&mut env, &parties, parties.buyer).unwrap();
+
+    env.update_config(|config| config.paused = PAUSE_SETTLEMENTS);
+    assert_eq!(resolve_appeal(&mut env, &parties, appeal_arbiter, true), Err(error(GigContractError::SettlementsPaused)));
+    advance_time(APPEAL_RESOLUTION_WINDOW);
+    assert_eq!(expire_appeal(&mut env, &parties), Err(error(GigContractError::SettlementsPaused)));
+
+    env.update_config(|config| config.paused = 0);
+    expire_appeal(&mut env, &parties).unwrap();
+}
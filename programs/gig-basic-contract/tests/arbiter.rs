@@ -0,0 +1,86 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use gig_basic_contract::{ accounts, instruction };
+use gig_basic_contract::constants::{ MIN_REVIEW_PERIOD, RESOLUTION_DELAY };
+use gig_basic_contract::errors::GigContractError;
+use gig_basic_contract::state::contract::ContractStatus;
+
+const DISPUTE: u64 = 50_000_000;
+
+fn start_contract(env: &mut Env, contract_id: &str, arbiter_fee_bps: u16) -> std::result::Result<(), ProgramError> {
+    let buyer = env.wallet();
+    let seller = env.wallet();
+    let arbiter = env.wallet();
+    env.token_account(buyer, 1_000 + DISPUTE);
+
+    let accounts = env.start_contract_accounts(buyer, seller, contract_id);
+    env.process(
+        accounts,
+        instruction::StartContract {
+            contract_id: contract_id.to_string(),
+            amount: 1_000,
+            dispute: DISPUTE,
+            deadline: now() + 30 * 24 * 60 * 60,
+            review_period: MIN_REVIEW_PERIOD,
+            require_verified: false,
+            arbiter: Some(arbiter),
+            arbiter_fee_bps,
+        },
+    )
+}
+
+fn set_max_arbiter_fee(env: &mut Env, admin: Pubkey, max_arbiter_fee_bps: u16) -> std::result::Result<(), ProgramError> {
+    env.process(
+        accounts::SetMaxArbiterFeeContext { admin, config: config_key() },
+        instruction::SetMaxArbiterFee { max_arbiter_fee_bps },
+    )
+}
+
+#[test]
+fn arbiter_fee_is_capped_by_config() {
+    let mut env = Env::new();
+
+    // Arbiters get no share of the platform fee until the admin allows it
+    assert_eq!(start_contract(&mut env, "first", 1), Err(error(GigContractError::InvalidShare)));
+    start_contract(&mut env, "first", 0).unwrap();
+
+    let admin = env.admin;
+    set_max_arbiter_fee(&mut env, admin, 2_000).unwrap();
+    assert_eq!(start_contract(&mut env, "second", 2_001), Err(error(GigContractError::InvalidShare)));
+    start_contract(&mut env, "second", 2_000).unwrap();
+}
+
+#[test]
+fn only_admin_sets_max_arbiter_fee() {
+    let mut env = Env::new();
+    let wallet = env.wallet();
+    assert_eq!(set_max_arbiter_fee(&mut env, wallet, 1_000), Err(error(GigContractError::InvalidAdmin)));
+
+    let admin = env.admin;
+    assert_eq!(set_max_arbiter_fee(&mut env, admin, 10_001), Err(error(GigContractError::InvalidShare)));
+    assert_eq!(env.config().max_arbiter_fee_bps, 0);
+}
+
+#[test]
+fn resolver_is_paid_from_forfeited_collateral() {
+    let mut env = Env::new();
+    let parties = env.active_contract(1_000, 100);
+    env.update_contract(&parties, |contract| {
+        contract.status = ContractStatus::Dispute;
+        contract.dispute_raiser = parties.seller;
+    });
+    let admin = env.admin;
+    env.token_account(admin, 0);
+
+    env.admin_approve(&parties, admin, 1).unwrap();
+    advance_time(RESOLUTION_DELAY);
+    assert_eq!(env.execute_resolution(&parties, None), Err(error(GigContractError::InvalidArbiter)));
+    env.execute_resolution(&parties, Some(ata(&admin))).unwrap();
+
+    // Half of the buyer's forfeited collateral goes to the admin, who gets no share of the platform fee
+    assert_eq!(env.balance(admin), 50);
+    assert_eq!(env.balance(config_key()), 100 + 50);
+    assert_eq!(env.balance(parties.seller), 900 + 100);
+}
@@ -0,0 +1,105 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::AccountMeta;
+use anchor_spl::token::spl_token;
+use common::*;
+use gig_basic_contract::{ accounts, instruction };
+use gig_basic_contract::constants::{ PAUSE_SETTLEMENTS, RESOLUTION_DELAY };
+use gig_basic_contract::errors::GigContractError;
+use gig_basic_contract::state::contract::ContractStatus;
+
+// Dispute raised by buyer and decided for the seller by the admin
+fn resolved_contract(env: &mut Env, dispute: u64) -> Parties {
+    let parties = env.active_contract(1_000, dispute);
+    env.update_contract(&parties, |contract| {
+        contract.status = ContractStatus::Dispute;
+        contract.dispute_raiser = parties.buyer;
+    });
+    let admin = env.admin;
+    env.admin_approve(&parties, admin, 1).unwrap();
+    parties
+}
+
+fn group(parties: &Parties, arbiter_ata: Pubkey) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new(parties.contract, false),
+        AccountMeta::new(ata(&parties.contract), false),
+        AccountMeta::new(ata(&parties.seller), false),
+        AccountMeta::new(ata(&parties.buyer), false),
+        AccountMeta::new(arbiter_ata, false),
+        AccountMeta::new(profile_key(&parties.buyer), false),
+        AccountMeta::new(profile_key(&parties.seller), false),
+    ]
+}
+
+fn execute_resolution_many(env: &mut Env, groups: Vec<AccountMeta>) -> std::result::Result<(), ProgramError> {
+    let signer = env.wallet();
+    env.process_with(
+        accounts::ExecuteResolutionManyContext {
+            signer,
+            config: config_key(),
+            treasury_ata: ata(&config_key()),
+            token_program: spl_token::ID,
+        },
+        instruction::ExecuteResolutionMany {},
+        groups,
+    )
+}
+
+#[test]
+fn batch_executes_every_group() {
+    let mut env = Env::new();
+    let admin = env.admin;
+    env.token_account(admin, 0);
+    let first = resolved_contract(&mut env, 100);
+    let second = resolved_contract(&mut env, 0);
+    advance_time(RESOLUTION_DELAY);
+
+    // Nothing is forfeited in the second contract, so its arbiter slot is not read
+    let filler = env.wallet();
+    let mut groups = group(&first, ata(&admin));
+    groups.extend(group(&second, filler));
+    execute_resolution_many(&mut env, groups).unwrap();
+
+    for parties in [&first, &second] {
+        assert!(env.contract(&parties.buyer, &parties.contract_id).status == ContractStatus::Completed);
+        assert_eq!(env.profile(parties.seller).volume, 900);
+    }
+    assert_eq!(env.balance(first.seller), 900 + 100);
+    assert_eq!(env.balance(second.seller), 900);
+    assert_eq!(env.balance(admin), 50);
+}
+
+#[test]
+fn batch_checks_arbiter_account_when_paid() {
+    let mut env = Env::new();
+    let parties = resolved_contract(&mut env, 100);
+    advance_time(RESOLUTION_DELAY);
+
+    let wallet = env.wallet();
+    env.token_account(wallet, 0);
+    assert_eq!(execute_resolution_many(&mut env, group(&parties, ata(&wallet))), Err(error(GigContractError::InvalidBatchAccount)));
+}
+
+#[test]
+fn batch_rejects_incomplete_group() {
+    let mut env = Env::new();
+    let parties = resolved_contract(&mut env, 0);
+    advance_time(RESOLUTION_DELAY);
+
+    let mut groups = group(&parties, parties.buyer);
+    groups.pop();
+    assert_eq!(execute_resolution_many(&mut env, groups), Err(error(GigContractError::InvalidBatchAccount)));
+    assert_eq!(execute_resolution_many(&mut env, vec![]), Err(error(GigContractError::BatchTooLarge)));
+}
+
+#[test]
+fn batch_is_blocked_by_settlement_pause() {
+    let mut env = Env::new();
+    let parties = resolved_contract(&mut env, 0);
+    advance_time(RESOLUTION_DELAY);
+
+    env.update_config(|config| config.paused = PAUSE_SETTLEMENTS);
+    assert_eq!(execute_resolution_many(&mut env, group(&parties, parties.buyer)), Err(error(GigContractError::SettlementsPaused)));
+}
@@ -0,0 +1,545 @@
+// In-process harness running instructions through the program entrypoint.
+// Clock and rent come from stubbed sysvars, token transfers and account creation
+// are applied by a stubbed CPI, everything else runs the real handlers.
+#![allow(dead_code)]
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::sync::Once;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    entrypoint::ProgramResult,
+    instruction::{ AccountMeta, Instruction },
+    program_pack::Pack,
+    program_stubs::{ self, SyscallStubs },
+    program_utils::limited_deserialize,
+    system_instruction::SystemInstruction,
+    system_program,
+    sysvar,
+};
+use anchor_lang::{ AccountDeserialize, AccountSerialize, InstructionData, ToAccountMetas };
+use anchor_spl::associated_token::{ self, get_associated_token_address };
+use anchor_spl::token::spl_token::{ self, instruction::TokenInstruction, state::Account as TokenState };
+
+use gig_basic_contract::{ accounts, instruction };
+use gig_basic_contract::constants::*;
+use gig_basic_contract::errors::GigContractError;
+use gig_basic_contract::state::config::Config;
+use gig_basic_contract::state::contract::{ Contract, ContractStatus };
+use gig_basic_contract::state::profile::UserProfile;
+
+pub const START: i64 = 1_700_000_000;
+
+thread_local! {
+    static NOW: Cell<i64> = const { Cell::new(START) };
+}
+
+struct Stubs;
+
+impl SyscallStubs for Stubs {
+    fn sol_log(&self, _message: &str) {}
+
+    fn sol_log_data(&self, _fields: &[&[u8]]) {}
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = Clock { unix_timestamp: NOW.with(|now| now.get()), ..Clock::default() };
+        unsafe { *(var_addr as *mut Clock) = clock };
+        0
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { *(var_addr as *mut Rent) = Rent::default() };
+        0
+    }
+
+    fn sol_invoke_signed(&self, instruction: &Instruction, account_infos: &[AccountInfo], signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        let find = |index: usize| {
+            account_infos
+                .iter()
+                .find(|info| *info.key == instruction.accounts[index].pubkey)
+                .ok_or(ProgramError::NotEnoughAccountKeys)
+        };
+
+        if instruction.program_id == spl_token::ID {
+            let TokenInstruction::Transfer { amount } = TokenInstruction::unpack(&instruction.data)? else {
+                return Err(ProgramError::InvalidInstructionData);
+            };
+            let (from, to, authority) = (find(0)?, find(1)?, find(2)?);
+
+            let mut source = TokenState::unpack(&from.data.borrow())?;
+            // Program derived authorities sign with their seeds like in the runtime
+            let signed = authority.is_signer || signers_seeds
+                .iter()
+                .any(|seeds| Pubkey::create_program_address(seeds, &gig_basic_contract::ID).is_ok_and(|key| key == *authority.key));
+            if source.owner != *authority.key || !signed {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            source.amount = source.amount.checked_sub(amount).ok_or(ProgramError::InsufficientFunds)?;
+            TokenState::pack(source, &mut from.data.borrow_mut())?;
+
+            let mut destination = TokenState::unpack(&to.data.borrow())?;
+            destination.amount += amount;
+            TokenState::pack(destination, &mut to.data.borrow_mut())?;
+            return Ok(());
+        }
+
+        if instruction.program_id == associated_token::ID {
+            let (payer, account, wallet, mint) = (find(0)?, find(1)?, find(2)?, find(3)?);
+            if !account.data_is_empty() {
+                return Ok(());
+            }
+            let lamports = Rent::default().minimum_balance(TokenState::LEN);
+            **payer.try_borrow_mut_lamports()? -= lamports;
+            **account.try_borrow_mut_lamports()? += lamports;
+            *account.data.borrow_mut() = Box::leak(token_data(*mint.key, *wallet.key, 0).into_boxed_slice());
+            account.assign(&spl_token::ID);
+            return Ok(());
+        }
+
+        if instruction.program_id == system_program::ID {
+            let system_instruction: SystemInstruction = limited_deserialize(&instruction.data, 1024)
+                .map_err(|_| ProgramError::InvalidInstructionData)?;
+            match system_instruction {
+                SystemInstruction::CreateAccount { lamports, space, owner } => {
+                    let (from, to) = (find(0)?, find(1)?);
+                    **from.try_borrow_mut_lamports()? -= lamports;
+                    **to.try_borrow_mut_lamports()? += lamports;
+                    *to.data.borrow_mut() = Box::leak(vec![0; space as usize].into_boxed_slice());
+                    to.assign(&owner);
+                }
+                SystemInstruction::Transfer { lamports } => {
+                    let (from, to) = (find(0)?, find(1)?);
+                    **from.try_borrow_mut_lamports()? -= lamports;
+                    **to.try_borrow_mut_lamports()? += lamports;
+                }
+                SystemInstruction::Allocate { space } => {
+                    *find(0)?.data.borrow_mut() = Box::leak(vec![0; space as usize].into_boxed_slice());
+                }
+                SystemInstruction::Assign { owner } => find(0)?.assign(&owner),
+                _ => return Err(ProgramError::InvalidInstructionData),
+            }
+            return Ok(());
+        }
+
+        Err(ProgramError::IncorrectProgramId)
+    }
+}
+
+#[derive(Clone)]
+struct TestAccount {
+    lamports: u64,
+    data: Vec<u8>,
+    owner: Pubkey,
+    executable: bool,
+}
+
+pub struct Env {
+    accounts: HashMap<Pubkey, TestAccount>,
+    pub admin: Pubkey,
+    pub guardian: Pubkey,
+    pub treasury_authority: Pubkey,
+}
+
+impl Env {
+    // Fresh program state with an initialized config and treasury
+    pub fn new() -> Self {
+        static STUBS: Once = Once::new();
+        STUBS.call_once(|| {
+            program_stubs::set_syscall_stubs(Box::new(Stubs));
+        });
+        set_time(START);
+
+        let mut env = Env {
+            accounts: HashMap::new(),
+            admin: Pubkey::new_unique(),
+            guardian: Pubkey::new_unique(),
+            treasury_authority: Pubkey::new_unique(),
+        };
+        for program in [gig_basic_contract::ID, spl_token::ID, associated_token::ID, system_program::ID] {
+            env.accounts.insert(program, TestAccount {
+                lamports: 1,
+                data: vec![],
+                owner: Pubkey::default(),
+                executable: true,
+            });
+        }
+
+        let config = Config {
+            admin: env.admin,
+            pending_admin: Pubkey::default(),
+            admin_timelock: 0,
+            admin_transfer_ready_at: 0,
+            treasury_authority: env.treasury_authority,
+            pending_treasury_authority: Pubkey::default(),
+            treasury_transfer_ready_at: 0,
+            guardian: env.guardian,
+            verifier: Pubkey::new_unique(),
+            appeal_window: 0,
+            appeal_bond: 0,
+            appeal_arbiters: [Pubkey::default(); MAX_APPEAL_ARBITERS],
+            max_arbiter_fee_bps: 0,
+            paused: 0,
+            bump: Pubkey::find_program_address(&[CONFIG_SEED.as_bytes()], &gig_basic_contract::ID).1,
+        };
+        env.set_account(config_key(), &config, Config::LEN + 8);
+
+        let mint = spl_token::state::Mint { decimals: DECIMAL as u8, is_initialized: true, ..Default::default() };
+        let mut data = vec![0; spl_token::state::Mint::LEN];
+        spl_token::state::Mint::pack(mint, &mut data).unwrap();
+        env.accounts.insert(PAY_TOKEN_MINT_ADDRESS, TestAccount { lamports: 1, data, owner: spl_token::ID, executable: false });
+
+        // Rent sysvar as bincode: lamports_per_byte_year, exemption_threshold, burn_percent
+        let rent = Rent::default();
+        let mut data = rent.lamports_per_byte_year.to_le_bytes().to_vec();
+        data.extend(rent.exemption_threshold.to_le_bytes());
+        data.push(rent.burn_percent);
+        env.accounts.insert(sysvar::rent::ID, TestAccount { lamports: 1, data, owner: sysvar::ID, executable: false });
+        env.token_account(config_key(), 0);
+        env
+    }
+
+    pub fn wallet(&mut self) -> Pubkey {
+        let key = Pubkey::new_unique();
+        self.accounts.insert(key, TestAccount {
+            lamports: 1_000_000_000_000,
+            data: vec![],
+            owner: system_program::ID,
+            executable: false,
+        });
+        key
+    }
+
+    pub fn set_account<T: AccountSerialize>(&mut self, key: Pubkey, account: &T, space: usize) {
+        let mut data = Vec::with_capacity(space);
+        account.try_serialize(&mut data).unwrap();
+        data.resize(space, 0);
+        self.accounts.insert(key, TestAccount {
+            lamports: Rent::default().minimum_balance(space),
+            data,
+            owner: gig_basic_contract::ID,
+            executable: false,
+        });
+    }
+
+    pub fn account<T: AccountDeserialize>(&self, key: Pubkey) -> T {
+        T::try_deserialize(&mut &self.accounts[&key].data[..]).unwrap()
+    }
+
+    pub fn exists(&self, key: Pubkey) -> bool {
+        self.accounts.get(&key).is_some_and(|account| !account.data.is_empty())
+    }
+
+    pub fn config(&self) -> Config {
+        self.account(config_key())
+    }
+
+    pub fn update_config(&mut self, update: impl FnOnce(&mut Config)) {
+        let mut config = self.config();
+        update(&mut config);
+        self.set_account(config_key(), &config, Config::LEN + 8);
+    }
+
+    // Stores the contract at its PDA, amount and collateral are expected in the contract ATA
+    pub fn set_contract(&mut self, contract: &Contract) -> Pubkey {
+        let key = contract_key(&contract.buyer, &contract.contract_id);
+        self.set_account(key, contract, Contract::LEN + 8);
+        key
+    }
+
+    pub fn contract(&self, buyer: &Pubkey, contract_id: &str) -> Contract {
+        self.account(contract_key(buyer, contract_id))
+    }
+
+    pub fn set_profile(&mut self, wallet: Pubkey) -> Pubkey {
+        let (key, bump) = Pubkey::find_program_address(&[PROFILE_SEED.as_bytes(), wallet.as_ref()], &gig_basic_contract::ID);
+        let profile = UserProfile {
+            wallet,
+            contract_count: 0,
+            buyer_count: 0,
+            seller_count: 0,
+            completed_count: 0,
+            volume: 0,
+            disputes_raised: 0,
+            disputes_lost: 0,
+            rating_sum: 0,
+            rating_count: 0,
+            bump,
+        };
+        self.set_account(key, &profile, UserProfile::LEN + 8);
+        key
+    }
+
+    pub fn profile(&self, wallet: Pubkey) -> UserProfile {
+        self.account(profile_key(&wallet))
+    }
+
+    // Creates the paytoken ATA of owner holding amount
+    pub fn token_account(&mut self, owner: Pubkey, amount: u64) -> Pubkey {
+        let key = get_associated_token_address(&owner, &PAY_TOKEN_MINT_ADDRESS);
+        self.accounts.insert(key, TestAccount {
+            lamports: Rent::default().minimum_balance(TokenState::LEN),
+            data: token_data(PAY_TOKEN_MINT_ADDRESS, owner, amount),
+            owner: spl_token::ID,
+            executable: false,
+        });
+        key
+    }
+
+    pub fn balance(&self, owner: Pubkey) -> u64 {
+        let key = get_associated_token_address(&owner, &PAY_TOKEN_MINT_ADDRESS);
+        TokenState::unpack(&self.accounts[&key].data).unwrap().amount
+    }
+
+    // Runs one instruction, state is only kept when it succeeds like a transaction
+    pub fn process(&mut self, accounts: impl ToAccountMetas, data: impl InstructionData) -> std::result::Result<(), ProgramError> {
+        self.process_with(accounts, data, vec![])
+    }
+
+    pub fn process_with(
+        &mut self,
+        accounts: impl ToAccountMetas,
+        data: impl InstructionData,
+        remaining_accounts: Vec<AccountMeta>,
+    ) -> std::result::Result<(), ProgramError> {
+        let mut metas = accounts.to_account_metas(None);
+        metas.extend(remaining_accounts);
+
+        let mut infos: HashMap<Pubkey, AccountInfo<'static>> = HashMap::new();
+        for meta in &metas {
+            infos.entry(meta.pubkey).or_insert_with(|| {
+                let account = self.accounts.get(&meta.pubkey).cloned().unwrap_or(TestAccount {
+                    lamports: 0,
+                    data: vec![],
+                    owner: system_program::ID,
+                    executable: false,
+                });
+                AccountInfo::new(
+                    Box::leak(Box::new(meta.pubkey)),
+                    false,
+                    false,
+                    Box::leak(Box::new(account.lamports)),
+                    Box::leak(account.data.into_boxed_slice()),
+                    Box::leak(Box::new(account.owner)),
+                    account.executable,
+                    0,
+                )
+            });
+        }
+
+        let account_infos: Vec<AccountInfo<'static>> = metas
+            .iter()
+            .map(|meta| {
+                let mut info = infos[&meta.pubkey].clone();
+                info.is_signer = meta.is_signer;
+                info.is_writable = meta.is_writable;
+                info
+            })
+            .collect();
+        let account_infos = Box::leak(account_infos.into_boxed_slice());
+
+        gig_basic_contract::entry(&gig_basic_contract::ID, account_infos, &data.data())?;
+
+        for (key, info) in infos {
+            self.accounts.insert(key, TestAccount {
+                lamports: info.lamports(),
+                data: info.data.borrow().to_vec(),
+                owner: *info.owner,
+                executable: info.executable,
+            });
+        }
+        Ok(())
+    }
+}
+
+// Parties of a contract with their token accounts and profiles set up
+pub struct Parties {
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub contract_id: String,
+    pub contract: Pubkey,
+}
+
+impl Env {
+    // Active one-time contract with amount and both collaterals held in the contract ATA
+    pub fn active_contract(&mut self, amount: u64, dispute: u64) -> Parties {
+        let buyer = self.wallet();
+        let seller = self.wallet();
+        let contract_id = "contract".to_string();
+        let contract = Contract {
+            buyer,
+            seller,
+            contract_id: contract_id.clone(),
+            start_time: now(),
+            deadline: now() + 30 * 24 * 60 * 60,
+            amount,
+            dispute,
+            seller_share_bps: BPS_DENOMINATOR,
+            buyer_proposal_bps: BPS_DENOMINATOR,
+            review_period: MIN_REVIEW_PERIOD,
+            status: ContractStatus::Active,
+            ..Contract::default()
+        };
+        let key = self.set_contract(&contract);
+        self.token_account(key, amount + 2 * dispute);
+        self.token_account(buyer, 0);
+        self.token_account(seller, 0);
+        self.set_profile(buyer);
+        self.set_profile(seller);
+
+        Parties { buyer, seller, contract_id, contract: key }
+    }
+
+    pub fn update_contract(&mut self, parties: &Parties, update: impl FnOnce(&mut Contract)) {
+        let mut contract = self.contract(&parties.buyer, &parties.contract_id);
+        update(&mut contract);
+        self.set_contract(&contract);
+    }
+
+    pub fn buyer_approve(&mut self, parties: &Parties, seller_share_bps: u16) -> std::result::Result<(), ProgramError> {
+        self.process(
+            accounts::BuyerApproveContext {
+                buyer: parties.buyer,
+                contract: parties.contract,
+                seller_ata: ata(&parties.seller),
+                buyer_ata: ata(&parties.buyer),
+                contract_ata: ata(&parties.contract),
+                token_program: spl_token::ID,
+                associated_token_program: associated_token::ID,
+                system_program: system_program::ID,
+            },
+            instruction::BuyerApprove { contract_id: parties.contract_id.clone(), seller_share_bps },
+        )
+    }
+
+    pub fn seller_approve(&mut self, parties: &Parties, seller_satisfied: bool) -> std::result::Result<(), ProgramError> {
+        self.process(
+            accounts::SellerApproveContext {
+                seller: parties.seller,
+                contract: parties.contract,
+                buyer_profile: profile_key(&parties.buyer),
+                seller_profile: profile_key(&parties.seller),
+                seller_ata: ata(&parties.seller),
+                buyer_ata: ata(&parties.buyer),
+                config: config_key(),
+                treasury_ata: ata(&config_key()),
+                contract_ata: ata(&parties.contract),
+                token_program: spl_token::ID,
+                associated_token_program: associated_token::ID,
+                system_program: system_program::ID,
+            },
+            instruction::SellerApprove { contract_id: parties.contract_id.clone(), seller_satisfied },
+        )
+    }
+}
+
+fn token_data(mint: Pubkey, owner: Pubkey, amount: u64) -> Vec<u8> {
+    let state = TokenState {
+        mint,
+        owner,
+        amount,
+        state: spl_token::state::AccountState::Initialized,
+        ..TokenState::default()
+    };
+    let mut data = vec![0; TokenState::LEN];
+    TokenState::pack(state, &mut data).unwrap();
+    data
+}
+
+impl Env {
+    pub fn admin_approve(&mut self, parties: &Parties, signer: Pubkey, decision: u8) -> std::result::Result<(), ProgramError> {
+        self.process(
+            accounts::AdminApproveContext { admin: signer, contract: parties.contract, config: config_key() },
+            instruction::AdminApprove { contract_id: parties.contract_id.clone(), decision },
+        )
+    }
+
+    pub fn execute_resolution(&mut self, parties: &Parties, arbiter_ata: Option<Pubkey>) -> std::result::Result<(), ProgramError> {
+        self.process(
+            accounts::ExecuteResolutionContext {
+                signer: parties.buyer,
+                contract: parties.contract,
+                buyer_profile: profile_key(&parties.buyer),
+                seller_profile: profile_key(&parties.seller),
+                seller_ata: ata(&parties.seller),
+                buyer_ata: ata(&parties.buyer),
+                config: config_key(),
+                treasury_ata: ata(&config_key()),
+                arbiter_ata,
+                contract_ata: ata(&parties.contract),
+                token_program: spl_token::ID,
+                associated_token_program: associated_token::ID,
+                system_program: system_program::ID,
+            },
+            instruction::ExecuteResolution { contract_id: parties.contract_id.clone() },
+        )
+    }
+
+    // Accounts of start_contract and the other instructions creating contracts
+    pub fn start_contract_accounts(&mut self, buyer: Pubkey, seller: Pubkey, contract_id: &str) -> accounts::StartContractContext {
+        let profile = self.next_page_index(buyer);
+        let page = Pubkey::find_program_address(
+            &[PROFILE_PAGE_SEED.as_bytes(), buyer.as_ref(), &profile.to_le_bytes()],
+            &gig_basic_contract::ID,
+        ).0;
+        let contract = contract_key(&buyer, contract_id);
+        accounts::StartContractContext {
+            buyer,
+            contract,
+            buyer_profile: profile_key(&buyer),
+            buyer_page: page,
+            seller,
+            buyer_ban: ban_key(&buyer),
+            seller_ban: ban_key(&seller),
+            config: config_key(),
+            pay_token_mint: PAY_TOKEN_MINT_ADDRESS,
+            buyer_ata: ata(&buyer),
+            contract_ata: ata(&contract),
+            token_program: spl_token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        }
+    }
+
+    // Page index of the next contract in the wallet's profile
+    fn next_page_index(&self, wallet: Pubkey) -> u32 {
+        if self.exists(profile_key(&wallet)) { self.profile(wallet).page_index() } else { 0 }
+    }
+}
+
+pub fn set_time(timestamp: i64) {
+    NOW.with(|now| now.set(timestamp));
+}
+
+pub fn advance_time(seconds: u32) {
+    NOW.with(|now| now.set(now.get() + seconds as i64));
+}
+
+pub fn now() -> u32 {
+    NOW.with(|now| now.get()) as u32
+}
+
+pub fn error(error: GigContractError) -> ProgramError {
+    anchor_lang::error::Error::from(error).into()
+}
+
+pub fn config_key() -> Pubkey {
+    Pubkey::find_program_address(&[CONFIG_SEED.as_bytes()], &gig_basic_contract::ID).0
+}
+
+pub fn contract_key(buyer: &Pubkey, contract_id: &str) -> Pubkey {
+    Pubkey::find_program_address(&[CONTRACT_SEED.as_bytes(), buyer.as_ref(), contract_id.as_bytes()], &gig_basic_contract::ID).0
+}
+
+pub fn profile_key(wallet: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[PROFILE_SEED.as_bytes(), wallet.as_ref()], &gig_basic_contract::ID).0
+}
+
+pub fn ban_key(wallet: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[BANNED_SEED.as_bytes(), wallet.as_ref()], &gig_basic_contract::ID).0
+}
+
+pub fn ata(owner: &Pubkey) -> Pubkey {
+    get_associated_token_address(owner, &PAY_TOKEN_MINT_ADDRESS)
+}
@@ -0,0 +1,86 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use gig_basic_contract::{ accounts, instruction };
+use gig_basic_contract::constants::RESOLUTION_DELAY;
+use gig_basic_contract::errors::GigContractError;
+use gig_basic_contract::state::contract::ContractStatus;
+
+fn set_guardian(env: &mut Env, signer: Pubkey, guardian: Pubkey) -> std::result::Result<(), ProgramError> {
+    env.process(
+        accounts::SetGuardianContext { guardian: signer, config: config_key() },
+        instruction::SetGuardian { guardian },
+    )
+}
+
+fn veto(env: &mut Env, parties: &Parties, guardian: Pubkey) -> std::result::Result<(), ProgramError> {
+    env.process(
+        accounts::VetoResolutionContext { guardian, contract: parties.contract, config: config_key() },
+        instruction::VetoResolution { contract_id: parties.contract_id.clone() },
+    )
+}
+
+// Dispute raised by buyer, the admin resolves it
+fn disputed_contract(env: &mut Env) -> Parties {
+    let parties = env.active_contract(1_000, 100);
+    env.update_contract(&parties, |contract| {
+        contract.status = ContractStatus::Dispute;
+        contract.dispute_raiser = parties.buyer;
+    });
+    let admin = env.admin;
+    env.token_account(admin, 0);
+    parties
+}
+
+#[test]
+fn only_guardian_rotates_guardian() {
+    let mut env = Env::new();
+    let admin = env.admin;
+    let guardian = env.guardian;
+    let new_guardian = env.wallet();
+
+    assert_eq!(set_guardian(&mut env, admin, admin), Err(error(GigContractError::InvalidGuardian)));
+    assert_eq!(set_guardian(&mut env, guardian, Pubkey::default()), Err(error(GigContractError::InvalidGuardian)));
+
+    set_guardian(&mut env, guardian, new_guardian).unwrap();
+    assert_eq!(env.config().guardian, new_guardian);
+    assert_eq!(set_guardian(&mut env, guardian, guardian), Err(error(GigContractError::InvalidGuardian)));
+}
+
+#[test]
+fn veto_sends_dispute_back_to_admin() {
+    let mut env = Env::new();
+    let parties = disputed_contract(&mut env);
+    let admin = env.admin;
+    let guardian = env.guardian;
+
+    env.admin_approve(&parties, admin, 1).unwrap();
+    assert_eq!(veto(&mut env, &parties, admin), Err(error(GigContractError::InvalidGuardian)));
+    veto(&mut env, &parties, guardian).unwrap();
+
+    let contract = env.contract(&parties.buyer, &parties.contract_id);
+    assert!(contract.status == ContractStatus::Dispute);
+    assert_eq!(contract.decision, 0);
+    assert_eq!(contract.resolved_by, Pubkey::default());
+    assert_eq!(contract.resolution_ready_at, 0);
+
+    // Nothing left to execute or veto until the admin decides again
+    advance_time(RESOLUTION_DELAY);
+    assert_eq!(env.execute_resolution(&parties, None), Err(error(GigContractError::NotReadyYet)));
+    assert_eq!(veto(&mut env, &parties, guardian), Err(error(GigContractError::CantVeto)));
+}
+
+#[test]
+fn executed_decision_cant_be_vetoed() {
+    let mut env = Env::new();
+    let parties = disputed_contract(&mut env);
+    let admin = env.admin;
+    let guardian = env.guardian;
+
+    env.admin_approve(&parties, admin, 1).unwrap();
+    advance_time(RESOLUTION_DELAY);
+    env.execute_resolution(&parties, Some(ata(&admin))).unwrap();
+
+    assert_eq!(veto(&mut env, &parties, guardian), Err(error(GigContractError::CantVeto)));
+}
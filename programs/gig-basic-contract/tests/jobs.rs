@@ -0,0 +1,120 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{ system_program, sysvar };
+use anchor_spl::{ associated_token, token::spl_token };
+use common::*;
+use gig_basic_contract::{ accounts, instruction };
+use gig_basic_contract::constants::{ APPLICATION_SEED, MIN_REVIEW_PERIOD, PAY_TOKEN_MINT_ADDRESS };
+use gig_basic_contract::errors::GigContractError;
+use gig_basic_contract::state::contract::ContractStatus;
+
+const DISPUTE: u64 = 50_000_000;
+const JOB_ID: &str = "job";
+
+fn post_job(env: &mut Env, buyer: Pubkey, bid_deadline: u32) -> std::result::Result<(), ProgramError> {
+    let start = env.start_contract_accounts(buyer, Pubkey::default(), JOB_ID);
+    env.process(
+        accounts::PostJobContext {
+            buyer,
+            contract: start.contract,
+            buyer_profile: start.buyer_profile,
+            buyer_page: start.buyer_page,
+            buyer_ban: start.buyer_ban,
+            config: config_key(),
+            pay_token_mint: PAY_TOKEN_MINT_ADDRESS,
+            buyer_ata: ata(&buyer),
+            contract_ata: ata(&start.contract),
+            token_program: spl_token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::PostJob {
+            contract_id: JOB_ID.to_string(),
+            amount: 1_000,
+            dispute: DISPUTE,
+            deadline: now() + 10_000,
+            review_period: MIN_REVIEW_PERIOD,
+            bid_deadline,
+            require_verified: false,
+        },
+    )
+}
+
+fn application_key(contract: &Pubkey, seller: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[APPLICATION_SEED.as_bytes(), contract.as_ref(), seller.as_ref()], &gig_basic_contract::ID).0
+}
+
+fn apply_job(env: &mut Env, contract: Pubkey, seller: Pubkey, bid: u64) -> std::result::Result<(), ProgramError> {
+    env.process(
+        accounts::ApplyJobContext {
+            seller,
+            contract,
+            application: application_key(&contract, &seller),
+            seller_ban: ban_key(&seller),
+            system_program: system_program::ID,
+        },
+        instruction::ApplyJob { contract_id: JOB_ID.to_string(), bid },
+    )
+}
+
+fn award_job(env: &mut Env, signer: Pubkey, buyer: Pubkey, seller: Pubkey) -> std::result::Result<(), ProgramError> {
+    let contract = contract_key(&buyer, JOB_ID);
+    env.process(
+        accounts::AwardJobContext {
+            signer,
+            contract,
+            application: application_key(&contract, &seller),
+            seller,
+            config: config_key(),
+            buyer_ata: ata(&buyer),
+            contract_ata: ata(&contract),
+            token_program: spl_token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::AwardJob { contract_id: JOB_ID.to_string() },
+    )
+}
+
+fn buyer(env: &mut Env) -> Pubkey {
+    let buyer = env.wallet();
+    env.token_account(buyer, 1_000 + DISPUTE);
+    buyer
+}
+
+#[test]
+fn bid_deadline_has_to_be_in_the_future() {
+    let mut env = Env::new();
+    let buyer = buyer(&mut env);
+
+    assert_eq!(post_job(&mut env, buyer, 0), Err(error(GigContractError::InvalidDeadline)));
+    assert_eq!(post_job(&mut env, buyer, now()), Err(error(GigContractError::InvalidDeadline)));
+    assert_eq!(post_job(&mut env, buyer, now() + 10_001), Err(error(GigContractError::InvalidDeadline)));
+
+    post_job(&mut env, buyer, now() + 100).unwrap();
+    assert!(env.contract(&buyer, JOB_ID).status == ContractStatus::Open);
+}
+
+#[test]
+fn applicant_cant_award_itself_before_bid_deadline() {
+    let mut env = Env::new();
+    let buyer = buyer(&mut env);
+    let seller = env.wallet();
+    post_job(&mut env, buyer, now() + 100).unwrap();
+    let contract = contract_key(&buyer, JOB_ID);
+
+    apply_job(&mut env, contract, seller, 800).unwrap();
+    assert_eq!(award_job(&mut env, seller, buyer, seller), Err(error(GigContractError::InvalidBuyer)));
+
+    // Lowest bid can be awarded by anyone once bidding is over
+    advance_time(101);
+    award_job(&mut env, seller, buyer, seller).unwrap();
+
+    let job = env.contract(&buyer, JOB_ID);
+    assert!(job.status == ContractStatus::Created);
+    assert_eq!(job.seller, seller);
+    assert_eq!(job.amount, 800);
+    assert_eq!(env.balance(buyer), 200);
+}
@@ -0,0 +1,115 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::AccountMeta;
+use common::*;
+use gig_basic_contract::{ accounts, instruction };
+use gig_basic_contract::constants::RESOLUTION_DELAY;
+use gig_basic_contract::errors::GigContractError;
+use gig_basic_contract::state::contract::{ ContractKind, ContractStatus };
+
+const DISPUTE: u64 = 50_000_000;
+
+// Active recurring contract of 4 periods of 250
+fn recurring_contract(env: &mut Env) -> Parties {
+    let parties = env.active_contract(1_000, 100);
+    env.update_contract(&parties, |contract| {
+        contract.kind = ContractKind::Recurring;
+        contract.period_count = 4;
+        contract.period_interval = 100;
+        contract.next_release_at = now() + 100;
+    });
+    parties
+}
+
+fn start_recurring_contract(env: &mut Env, buyer: Pubkey, amount_per_period: u64, period_count: u16, period_interval: u32) -> std::result::Result<(), ProgramError> {
+    let seller = env.wallet();
+    env.token_account(buyer, 10_000 + DISPUTE);
+    let accounts = env.start_contract_accounts(buyer, seller, "recurring");
+    env.process(
+        accounts,
+        instruction::StartRecurringContract {
+            contract_id: "recurring".to_string(),
+            amount_per_period,
+            dispute: DISPUTE,
+            period_count,
+            period_interval,
+            require_verified: false,
+        },
+    )
+}
+
+#[test]
+fn recurring_contract_escrows_every_period() {
+    let mut env = Env::new();
+    let buyer = env.wallet();
+    start_recurring_contract(&mut env, buyer, 2_500, 4, 100).unwrap();
+
+    let contract = env.contract(&buyer, "recurring");
+    assert_eq!(contract.amount, 10_000);
+    assert_eq!(contract.deadline, now() + 400);
+    assert_eq!(env.balance(contract_key(&buyer, "recurring")), 10_000 + DISPUTE);
+    assert_eq!(env.balance(buyer), 0);
+}
+
+#[test]
+fn recurring_contract_rejects_overflowing_terms() {
+    let mut env = Env::new();
+    let buyer = env.wallet();
+
+    assert_eq!(start_recurring_contract(&mut env, buyer, u64::MAX / 2, 3, 100), Err(error(GigContractError::InvalidAmount)));
+    assert_eq!(start_recurring_contract(&mut env, buyer, u64::MAX - DISPUTE + 1, 1, 100), Err(error(GigContractError::InvalidAmount)));
+    assert_eq!(start_recurring_contract(&mut env, buyer, 1, 2_000, 30 * 24 * 60 * 60), Err(error(GigContractError::InvalidPeriod)));
+}
+
+#[test]
+fn recurring_contract_cant_be_approved_at_once() {
+    let mut env = Env::new();
+    let parties = recurring_contract(&mut env);
+
+    assert_eq!(env.seller_approve(&parties, true), Err(error(GigContractError::CantRelease)));
+    assert_eq!(env.buyer_approve(&parties, 10_000), Err(error(GigContractError::CantRelease)));
+    assert!(env.contract(&parties.buyer, &parties.contract_id).status == ContractStatus::Active);
+}
+
+#[test]
+fn admin_cant_approve_pending_recurring_contract() {
+    let mut env = Env::new();
+    let parties = recurring_contract(&mut env);
+    env.update_contract(&parties, |contract| contract.status = ContractStatus::Pending);
+    let admin = env.admin;
+
+    assert_eq!(env.admin_approve(&parties, admin, 1), Err(error(GigContractError::NotReadyYet)));
+    assert_eq!(
+        env.process_with(
+            accounts::AdminApproveManyContext { admin, config: config_key() },
+            instruction::AdminApproveMany {},
+            vec![AccountMeta::new(parties.contract, false)],
+        ),
+        Err(error(GigContractError::NotReadyYet)),
+    );
+}
+
+#[test]
+fn objection_only_settles_the_due_period() {
+    let mut env = Env::new();
+    let parties = recurring_contract(&mut env);
+    let admin = env.admin;
+    env.token_account(admin, 0);
+
+    env.process(
+        accounts::ObjectPeriodContext { buyer: parties.buyer, contract: parties.contract },
+        instruction::ObjectPeriod { contract_id: parties.contract_id.clone() },
+    ).unwrap();
+    env.admin_approve(&parties, admin, 1).unwrap();
+
+    assert_eq!(env.execute_resolution(&parties, Some(ata(&admin))), Err(error(GigContractError::ResolutionNotReady)));
+    advance_time(RESOLUTION_DELAY);
+    env.execute_resolution(&parties, Some(ata(&admin))).unwrap();
+
+    // Seller is paid the due period, the 3 later periods go back to buyer
+    assert_eq!(env.balance(parties.seller), 225 + 100);
+    assert_eq!(env.balance(parties.buyer), 750);
+    assert_eq!(env.balance(config_key()) + env.balance(admin), 25 + 100);
+    assert_eq!(env.balance(parties.contract), 0);
+}
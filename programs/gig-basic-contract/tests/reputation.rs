@@ -0,0 +1,80 @@
+mod common;
+
+use anchor_lang::solana_program::system_program;
+use anchor_spl::{ associated_token, token::spl_token };
+use common::*;
+use gig_basic_contract::{ accounts, instruction };
+use gig_basic_contract::constants::RESOLUTION_DELAY;
+use gig_basic_contract::state::contract::{ ContractKind, ContractStatus };
+
+#[test]
+fn split_records_seller_payout_as_volume() {
+    let mut env = Env::new();
+    let parties = env.active_contract(1_000, 100);
+
+    env.buyer_approve(&parties, 3_000).unwrap();
+    env.seller_approve(&parties, true).unwrap();
+
+    for wallet in [parties.buyer, parties.seller] {
+        let profile = env.profile(wallet);
+        assert_eq!(profile.completed_count, 1);
+        assert_eq!(profile.volume, 270);
+    }
+}
+
+#[test]
+fn dispute_won_by_buyer_records_no_volume() {
+    let mut env = Env::new();
+    let parties = env.active_contract(1_000, 100);
+    env.update_contract(&parties, |contract| {
+        contract.status = ContractStatus::Dispute;
+        contract.dispute_raiser = parties.buyer;
+    });
+
+    let admin = env.admin;
+    env.token_account(admin, 0);
+    env.admin_approve(&parties, admin, 2).unwrap();
+    advance_time(RESOLUTION_DELAY);
+    env.execute_resolution(&parties, Some(ata(&admin))).unwrap();
+
+    let seller = env.profile(parties.seller);
+    assert_eq!(seller.completed_count, 1);
+    assert_eq!(seller.volume, 0);
+    assert_eq!(seller.disputes_lost, 1);
+    assert_eq!(env.profile(parties.buyer).volume, 0);
+}
+
+#[test]
+fn stopped_stream_records_only_the_vested_part() {
+    let mut env = Env::new();
+    let parties = env.active_contract(1_000, 100);
+    env.update_contract(&parties, |contract| {
+        contract.kind = ContractKind::Stream;
+        contract.deadline = now() + 1_000;
+        contract.released = 300;
+    });
+    env.token_account(parties.contract, 700 + 200);
+
+    advance_time(500);
+    env.process(
+        accounts::StopStreamContext {
+            buyer: parties.buyer,
+            contract: parties.contract,
+            buyer_profile: profile_key(&parties.buyer),
+            seller_profile: profile_key(&parties.seller),
+            seller_ata: ata(&parties.seller),
+            buyer_ata: ata(&parties.buyer),
+            config: config_key(),
+            treasury_ata: ata(&config_key()),
+            contract_ata: ata(&parties.contract),
+            token_program: spl_token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::StopStream { contract_id: parties.contract_id.clone() },
+    ).unwrap();
+
+    // 300 withdrawn earlier plus the 200 vested since, less the fee
+    assert_eq!(env.profile(parties.seller).volume, 300 + 180);
+    assert_eq!(env.profile(parties.buyer).completed_count, 1);
+}
@@ -0,0 +1,47 @@
+mod common;
+
+use common::*;
+use gig_basic_contract::errors::GigContractError;
+use gig_basic_contract::state::contract::ContractStatus;
+
+#[test]
+fn seller_accepting_split_settles_by_buyer_share() {
+    let mut env = Env::new();
+    let parties = env.active_contract(1_000, 100);
+
+    env.buyer_approve(&parties, 3_000).unwrap();
+    let contract = env.contract(&parties.buyer, &parties.contract_id);
+    assert!(contract.status == ContractStatus::Pending);
+    assert_eq!(contract.split_proposer, parties.buyer);
+
+    env.seller_approve(&parties, true).unwrap();
+    assert!(env.contract(&parties.buyer, &parties.contract_id).status == ContractStatus::Completed);
+
+    // 10% fee, 30% of the rest to seller, collateral back to both
+    assert_eq!(env.balance(parties.seller), 270 + 100);
+    assert_eq!(env.balance(parties.buyer), 630 + 100);
+    assert_eq!(env.balance(config_key()), 100);
+    assert_eq!(env.balance(parties.contract), 0);
+}
+
+#[test]
+fn seller_rejecting_split_raises_dispute() {
+    let mut env = Env::new();
+    let parties = env.active_contract(1_000, 100);
+
+    env.buyer_approve(&parties, 3_000).unwrap();
+    env.seller_approve(&parties, false).unwrap();
+
+    let contract = env.contract(&parties.buyer, &parties.contract_id);
+    assert!(contract.status == ContractStatus::Dispute);
+    assert_eq!(contract.dispute_raiser, parties.seller);
+    assert_eq!(env.balance(parties.contract), 1_200);
+}
+
+#[test]
+fn split_share_is_capped_at_full_release() {
+    let mut env = Env::new();
+    let parties = env.active_contract(1_000, 100);
+
+    assert_eq!(env.buyer_approve(&parties, 10_001), Err(error(GigContractError::InvalidShare)));
+}
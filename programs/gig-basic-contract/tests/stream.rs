@@ -0,0 +1,112 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::{ associated_token, token::spl_token };
+use common::*;
+use gig_basic_contract::{ accounts, instruction };
+use gig_basic_contract::constants::PROFILE_PAGE_SEED;
+use gig_basic_contract::errors::GigContractError;
+use gig_basic_contract::state::contract::{ ContractKind, ContractStatus };
+
+// Stream of 1000 over 1000 seconds waiting for the seller to activate it
+fn created_stream(env: &mut Env) -> Parties {
+    let parties = env.active_contract(1_000, 100);
+    env.update_contract(&parties, |contract| {
+        contract.kind = ContractKind::Stream;
+        contract.status = ContractStatus::Created;
+        contract.start_time = now();
+        contract.deadline = now() + 1_000;
+    });
+    env.token_account(parties.contract, 1_000 + 100);
+    env.token_account(parties.seller, 100);
+    parties
+}
+
+fn activate(env: &mut Env, parties: &Parties) -> std::result::Result<(), ProgramError> {
+    let seller_page = Pubkey::find_program_address(
+        &[PROFILE_PAGE_SEED.as_bytes(), parties.seller.as_ref(), &0u32.to_le_bytes()],
+        &gig_basic_contract::ID,
+    ).0;
+    env.process(
+        accounts::ActivateContractContext {
+            seller: parties.seller,
+            contract: parties.contract,
+            seller_profile: profile_key(&parties.seller),
+            seller_page,
+            verified: None,
+            seller_ban: ban_key(&parties.seller),
+            buyer_ban: ban_key(&parties.buyer),
+            config: config_key(),
+            seller_ata: ata(&parties.seller),
+            contract_ata: ata(&parties.contract),
+            token_program: spl_token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::ActivateContract { contract_id: parties.contract_id.clone(), arbiter: None },
+    )
+}
+
+fn withdraw_vested(env: &mut Env, parties: &Parties) -> std::result::Result<(), ProgramError> {
+    env.process(
+        accounts::WithdrawVestedContext {
+            seller: parties.seller,
+            contract: parties.contract,
+            buyer_profile: profile_key(&parties.buyer),
+            seller_profile: profile_key(&parties.seller),
+            seller_ata: ata(&parties.seller),
+            buyer_ata: ata(&parties.buyer),
+            config: config_key(),
+            treasury_ata: ata(&config_key()),
+            contract_ata: ata(&parties.contract),
+            token_program: spl_token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::WithdrawVested { contract_id: parties.contract_id.clone() },
+    )
+}
+
+#[test]
+fn stream_vests_from_activation() {
+    let mut env = Env::new();
+    let parties = created_stream(&mut env);
+
+    advance_time(400);
+    activate(&mut env, &parties).unwrap();
+    assert_eq!(env.contract(&parties.buyer, &parties.contract_id).start_time, now());
+
+    // Nothing vested for the time the seller was not working
+    assert_eq!(withdraw_vested(&mut env, &parties), Err(error(GigContractError::NothingVested)));
+
+    advance_time(300);
+    withdraw_vested(&mut env, &parties).unwrap();
+    assert_eq!(env.balance(parties.seller), 500 - 50);
+}
+
+#[test]
+fn stream_cant_be_activated_after_deadline() {
+    let mut env = Env::new();
+    let parties = created_stream(&mut env);
+
+    advance_time(1_000);
+    assert_eq!(activate(&mut env, &parties), Err(error(GigContractError::InvalidDeadline)));
+}
+
+#[test]
+fn stream_cant_be_approved_at_once() {
+    let mut env = Env::new();
+    let parties = created_stream(&mut env);
+    activate(&mut env, &parties).unwrap();
+
+    assert_eq!(env.seller_approve(&parties, true), Err(error(GigContractError::CantRelease)));
+    assert_eq!(env.buyer_approve(&parties, 10_000), Err(error(GigContractError::CantRelease)));
+
+    // Stream keeps vesting to the seller
+    advance_time(1_000);
+    withdraw_vested(&mut env, &parties).unwrap();
+    assert_eq!(env.balance(parties.seller), 900 + 100);
+    assert_eq!(env.balance(parties.buyer), 100);
+    assert!(env.contract(&parties.buyer, &parties.contract_id).status == ContractStatus::Completed);
+}
@@ -0,0 +1,63 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use gig_basic_contract::{ accounts, instruction };
+use gig_basic_contract::errors::GigContractError;
+
+fn propose(env: &mut Env, signer: Pubkey, new_treasury_authority: Pubkey) -> std::result::Result<(), ProgramError> {
+    env.process(
+        accounts::ProposeTreasuryAuthorityContext { treasury_authority: signer, config: config_key() },
+        instruction::ProposeTreasuryAuthority { new_treasury_authority },
+    )
+}
+
+fn accept(env: &mut Env, signer: Pubkey) -> std::result::Result<(), ProgramError> {
+    env.process(
+        accounts::AcceptTreasuryAuthorityContext { new_treasury_authority: signer, config: config_key() },
+        instruction::AcceptTreasuryAuthority {},
+    )
+}
+
+#[test]
+fn treasury_authority_is_handed_over_in_two_steps() {
+    let mut env = Env::new();
+    env.update_config(|config| config.admin_timelock = 100);
+    let treasury_authority = env.treasury_authority;
+    let new_treasury_authority = Pubkey::new_unique();
+
+    propose(&mut env, treasury_authority, new_treasury_authority).unwrap();
+    assert_eq!(env.config().treasury_authority, treasury_authority);
+
+    assert_eq!(accept(&mut env, new_treasury_authority), Err(error(GigContractError::TreasuryTransferLocked)));
+
+    advance_time(100);
+    assert_eq!(accept(&mut env, Pubkey::new_unique()), Err(error(GigContractError::InvalidPendingTreasuryAuthority)));
+    accept(&mut env, new_treasury_authority).unwrap();
+
+    let config = env.config();
+    assert_eq!(config.treasury_authority, new_treasury_authority);
+    assert_eq!(config.pending_treasury_authority, Pubkey::default());
+}
+
+#[test]
+fn only_treasury_authority_proposes_a_new_one() {
+    let mut env = Env::new();
+    let admin = env.admin;
+
+    assert_eq!(propose(&mut env, admin, admin), Err(error(GigContractError::InvalidTreasuryAuthority)));
+}
+
+#[test]
+fn proposing_default_key_cancels_the_handover() {
+    let mut env = Env::new();
+    let treasury_authority = env.treasury_authority;
+    let new_treasury_authority = Pubkey::new_unique();
+
+    propose(&mut env, treasury_authority, new_treasury_authority).unwrap();
+    propose(&mut env, treasury_authority, Pubkey::default()).unwrap();
+
+    assert_eq!(accept(&mut env, new_treasury_authority), Err(error(GigContractError::InvalidPendingTreasuryAuthority)));
+    assert_eq!(accept(&mut env, Pubkey::default()), Err(error(GigContractError::InvalidPendingTreasuryAuthority)));
+    assert_eq!(env.config().treasury_authority, treasury_authority);
+}